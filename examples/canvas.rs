@@ -0,0 +1,48 @@
+//! Draws a simple chart that follows the mouse in a canvas.
+
+#[macro_use]
+extern crate iup;
+
+use iup::prelude::*;
+use iup::control::{Canvas, Label};
+use iup::layout::VBox;
+use iup::draw::{DrawStyle, TextAlign};
+use iup::attrib::{Expand, RasterSize};
+
+fn main () {
+    iup::with_iup(|| {
+
+        let mut info = Label::with_title("Move the mouse over the canvas")
+                            .set_expand(Expand::Horizontal);
+
+        let canvas = Canvas::new()
+                        .set_rastersize(RasterSize::new(300, 200))
+                        .set_action(|(mut canvas, _, _): (Canvas, f32, f32)| {
+                            let mut dc = canvas.draw();
+                            let (w, h) = dc.size();
+
+                            dc.set_color((255, 255, 255))
+                              .set_style(DrawStyle::Fill)
+                              .rectangle(0, 0, w - 1, h - 1);
+
+                            dc.set_color((30, 50, 210))
+                              .set_style(DrawStyle::Stroke)
+                              .set_line_width(2)
+                              .polygon([(10, h - 10), (w / 4, h / 2), (w / 2, h * 2 / 3),
+                                        (w * 3 / 4, h / 4), (w - 10, 10)]);
+
+                            dc.set_color((0, 0, 0))
+                              .set_text_alignment(TextAlign::Center)
+                              .text_in("A simple chart", 0, 0, w, 20);
+                        })
+                        .set_motion_cb(move |(_, x, y, _)| {
+                            info.set_attrib("TITLE", format!("Mouse at {}x{}", x, y));
+                        });
+
+        Dialog::new(
+            VBox::new(elements![canvas, info])
+        ).set_title("Canvas")
+         .show()
+
+    }).unwrap();
+}
@@ -0,0 +1,46 @@
+//! Menu bar with checkable items, radio items and shortcuts.
+
+#[macro_use]
+extern crate iup;
+
+use iup::prelude::*;
+use iup::callback::key::Key;
+use iup::control::Label;
+use iup::menu::{Item, Submenu, Separator};
+
+fn main () {
+    iup::with_iup(|| {
+
+        let mut status = Label::with_title("Pick something from the menu")
+                                .set_attrib("EXPAND", "HORIZONTAL");
+
+        let menubar = menu![
+            Submenu::with_title("&File", menu![
+                Item::with_title("&New").set_accel(Key::ctrl('n'))
+                                        .set_action(move |_| {
+                                            status.set_attrib("TITLE", "New file");
+                                        }),
+                Separator::new(),
+                Item::with_title("E&xit").set_action(|_| CallbackReturn::Close),
+            ]),
+            Submenu::with_title("&View", menu![
+                Item::with_title("&Word Wrap").set_checkable(true).set_checked(true),
+                Submenu::with_title("&Zoom", menu![
+                    Item::with_title("50%").set_checkable(true),
+                    Item::with_title("100%").set_checkable(true).set_checked(true),
+                    Item::with_title("200%").set_checkable(true),
+                ].set_radio(true)),
+            ]),
+        ];
+
+        Dialog::new(status)
+            .set_attrib("TITLE", "Menu")
+            .set_attrib("SIZE", "200x80")
+            .set_menu(menubar)
+            .set_shortcut(Key::ctrl('n'), move |_| {
+                status.set_attrib("TITLE", "New file (from shortcut)");
+            })
+            .show()
+
+    }).unwrap();
+}
@@ -15,20 +15,20 @@ use std::cell::Cell;
 use iup::prelude::*;
 use iup::layout::{Radio, VBox};
 use iup::control::{Frame, Toggle, Label};
+use iup::attrib::Color;
 
-const RED: (u8, u8, u8)   = (255, 0, 0);
-const GREEN: (u8, u8, u8) = (0, 255, 0);
-const BLUE: (u8, u8, u8)  = (0, 0, 255);
+const RED: Color   = Color { r: 255, g: 0, b: 0, a: 255 };
+const GREEN: Color = Color { r: 0, g: 255, b: 0, a: 255 };
+const BLUE: Color  = Color { r: 0, g: 0, b: 255, a: 255 };
 
-fn change_color(mut dialog: Dialog, state: bool, color: Option<(u8, u8, u8)>)
-                                                                -> Option<(u8, u8, u8)> {
+fn change_color(mut dialog: Dialog, state: bool, color: Option<Color>) -> Option<Color> {
     if state {
         match color {
-            Some(rgb) => dialog.set_attrib_rgb("BGCOLOR", rgb),
+            Some(color) => dialog.set_bgcolor(color),
             None => dialog.clear_attrib("BGCOLOR"),
         };
     }
-    dialog.attrib_rgb("BGCOLOR")
+    dialog.bgcolor()
 }
 
 fn main() {
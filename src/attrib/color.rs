@@ -0,0 +1,170 @@
+//! Color values.
+use std::fmt;
+use std::str::FromStr;
+
+use attrib::ParseAttribError;
+use element;
+
+/// A RGBA color.
+///
+/// The string format is `"R G B"` or `"R G B A"` with decimal components from 0 to 255.
+/// Parsing also accepts the `"#RRGGBB"` and `"#RRGGBBAA"` hexadecimal formats and the names of
+/// the system colors (see `SystemColor`), e.g. `"DLGBGCOLOR"`.
+///
+/// Most attributes ignore the alpha component, it's used mostly by drawing and images.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    /// The alpha component, 255 is opaque.
+    pub a: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color { r: 0, g: 0, b: 0, a: 255 };
+    pub const WHITE: Color = Color { r: 255, g: 255, b: 255, a: 255 };
+
+    /// Creates an opaque color.
+    pub fn rgb(r: u8, g: u8, b: u8) -> Color {
+        Color { r, g, b, a: 255 }
+    }
+
+    /// Creates a color with transparency.
+    pub fn rgba(r: u8, g: u8, b: u8, a: u8) -> Color {
+        Color { r, g, b, a }
+    }
+
+    /// Gets the same color with no transparency, formatted in the `"R G B"` form.
+    pub fn opaque(self) -> Color {
+        Color { a: 255, ..self }
+    }
+
+    /// Gets the current value of a system color.
+    ///
+    /// Returns `None` if IUP is not initialized.
+    pub fn system(color: SystemColor) -> Option<Color> {
+        element::global(color.as_str()).and_then(|value| parse_components(&value).ok())
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((r, g, b): (u8, u8, u8)) -> Color {
+        Color::rgb(r, g, b)
+    }
+}
+
+impl From<(u8, u8, u8, u8)> for Color {
+    fn from((r, g, b, a): (u8, u8, u8, u8)) -> Color {
+        Color::rgba(r, g, b, a)
+    }
+}
+
+impl From<Color> for (u8, u8, u8) {
+    fn from(color: Color) -> (u8, u8, u8) {
+        (color.r, color.g, color.b)
+    }
+}
+
+impl From<Color> for (u8, u8, u8, u8) {
+    fn from(color: Color) -> (u8, u8, u8, u8) {
+        (color.r, color.g, color.b, color.a)
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseAttribError;
+    fn from_str(s: &str) -> Result<Color, ParseAttribError> {
+        let s = s.trim();
+        if let Some(hex) = s.strip_prefix('#') {
+            parse_hex(hex).ok_or_else(|| ParseAttribError::new(s))
+        } else if s.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            SystemColor::from_name(s).and_then(Color::system).ok_or_else(|| ParseAttribError::new(s))
+        } else {
+            parse_components(s)
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        if self.a == 255 {
+            write!(fmt, "{} {} {}", self.r, self.g, self.b)
+        } else {
+            write!(fmt, "{} {} {} {}", self.r, self.g, self.b, self.a)
+        }
+    }
+}
+
+/// Parses the `"R G B"` and `"R G B A"` formats.
+fn parse_components(s: &str) -> Result<Color, ParseAttribError> {
+    let values = s.split_whitespace()
+                  .map(|v| v.parse::<u8>())
+                  .collect::<Result<Vec<u8>, _>>()
+                  .map_err(|_| ParseAttribError::new(s))?;
+    match values[..] {
+        [r, g, b] => Ok(Color::rgb(r, g, b)),
+        [r, g, b, a] => Ok(Color::rgba(r, g, b, a)),
+        _ => Err(ParseAttribError::new(s)),
+    }
+}
+
+/// Parses the `RRGGBB` and `RRGGBBAA` hexadecimal formats, without the `#`.
+fn parse_hex(hex: &str) -> Option<Color> {
+    if (hex.len() != 6 && hex.len() != 8) || !hex.is_ascii() {
+        return None;
+    }
+    let component = |i: usize| u8::from_str_radix(&hex[i..i+2], 16).ok();
+    match (component(0), component(2), component(4)) {
+        (Some(r), Some(g), Some(b)) if hex.len() == 6 => Some(Color::rgb(r, g, b)),
+        (Some(r), Some(g), Some(b)) => component(6).map(|a| Color::rgba(r, g, b, a)),
+        _ => None,
+    }
+}
+
+/// A color of the system theme, available as a global attribute after IUP is initialized.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum SystemColor {
+    /// The background color of dialogs, `DLGBGCOLOR`.
+    DlgBg,
+    /// The text color of dialogs, `DLGFGCOLOR`.
+    DlgFg,
+    /// The background color of text fields and lists, `TXTBGCOLOR`.
+    TxtBg,
+    /// The text color of text fields and lists, `TXTFGCOLOR`.
+    TxtFg,
+    /// The background color of selected text, `TXTHLCOLOR`.
+    TxtHl,
+    /// The text color of links, `LINKFGCOLOR`.
+    LinkFg,
+    /// The background color of menus, `MENUBGCOLOR`.
+    MenuBg,
+}
+
+impl SystemColor {
+    /// Gets the name of the global attribute of this color.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SystemColor::DlgBg => "DLGBGCOLOR",
+            SystemColor::DlgFg => "DLGFGCOLOR",
+            SystemColor::TxtBg => "TXTBGCOLOR",
+            SystemColor::TxtFg => "TXTFGCOLOR",
+            SystemColor::TxtHl => "TXTHLCOLOR",
+            SystemColor::LinkFg => "LINKFGCOLOR",
+            SystemColor::MenuBg => "MENUBGCOLOR",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<SystemColor> {
+        match &*name.to_uppercase() {
+            "DLGBGCOLOR" => Some(SystemColor::DlgBg),
+            "DLGFGCOLOR" => Some(SystemColor::DlgFg),
+            "TXTBGCOLOR" => Some(SystemColor::TxtBg),
+            "TXTFGCOLOR" => Some(SystemColor::TxtFg),
+            "TXTHLCOLOR" => Some(SystemColor::TxtHl),
+            "LINKFGCOLOR" => Some(SystemColor::LinkFg),
+            "MENUBGCOLOR" => Some(SystemColor::MenuBg),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! Font values.
+use std::fmt;
+use std::str::FromStr;
+
+use attrib::ParseAttribError;
+
+/// A font description, as in the FONT attribute.
+///
+/// The string format is `"Face, Style Size"`, e.g. `"Times, Bold Italic 12"`, where the style
+/// is a space separated list of `Bold`, `Italic`, `Underline` and `Strikeout`, possibly empty.
+/// Parsing also accepts the format without the comma, e.g. `"Times Bold 12"`.
+///
+/// The size is in points, or in pixels when negative.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Font {
+    /// The typeface name, e.g. `"Times"`, `"Courier"` or `"Helvetica"`.
+    pub face: String,
+    /// The size in points, or in pixels when negative.
+    pub size: i32,
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub strikeout: bool,
+}
+
+impl Font {
+    /// Creates a font with the specified face and size and no style.
+    pub fn new<S: Into<String>>(face: S, size: i32) -> Font {
+        Font {
+            face: face.into(),
+            size,
+            bold: false,
+            italic: false,
+            underline: false,
+            strikeout: false,
+        }
+    }
+
+    /// Makes the font bold.
+    pub fn bold(mut self) -> Font {
+        self.bold = true;
+        self
+    }
+
+    /// Makes the font italic.
+    pub fn italic(mut self) -> Font {
+        self.italic = true;
+        self
+    }
+
+    /// Makes the font underlined.
+    pub fn underline(mut self) -> Font {
+        self.underline = true;
+        self
+    }
+
+    /// Makes the font struck out.
+    pub fn strikeout(mut self) -> Font {
+        self.strikeout = true;
+        self
+    }
+
+    /// Applies a style word to the font, returns `false` if the word is not a style.
+    fn apply_style(&mut self, word: &str) -> bool {
+        match &*word.to_uppercase() {
+            "BOLD" => self.bold = true,
+            "ITALIC" => self.italic = true,
+            "UNDERLINE" => self.underline = true,
+            "STRIKEOUT" => self.strikeout = true,
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl FromStr for Font {
+    type Err = ParseAttribError;
+    fn from_str(s: &str) -> Result<Font, ParseAttribError> {
+        let err = || ParseAttribError::new(s);
+        let s = s.trim();
+
+        let (face, rest) = match s.rfind(',') {
+            Some(i) => (s[..i].trim(), &s[i + 1..]),
+            None => ("", s),
+        };
+
+        // The size is always the last word, followed backwards by the style.
+        let mut words = rest.split_whitespace().collect::<Vec<_>>();
+        let size = words.pop().and_then(|w| w.parse().ok()).ok_or_else(err)?;
+
+        let mut font = Font::new(face, size);
+        if s.contains(',') {
+            if !words.iter().all(|word| font.apply_style(word)) {
+                return Err(err());
+            }
+        } else {
+            while words.last().is_some_and(|word| font.apply_style(word)) {
+                words.pop();
+            }
+            font.face = words.join(" ");
+        }
+
+        if font.face.is_empty() {
+            return Err(err());
+        }
+        Ok(font)
+    }
+}
+
+impl fmt::Display for Font {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{},", self.face)?;
+        if self.bold { fmt.write_str(" Bold")?; }
+        if self.italic { fmt.write_str(" Italic")?; }
+        if self.underline { fmt.write_str(" Underline")?; }
+        if self.strikeout { fmt.write_str(" Strikeout")?; }
+        write!(fmt, " {}", self.size)
+    }
+}
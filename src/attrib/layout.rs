@@ -0,0 +1,93 @@
+//! Layout values.
+use std::fmt;
+use std::str::FromStr;
+
+use attrib::ParseAttribError;
+
+/// How an element fills the empty space of its container, as in the EXPAND attribute.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Expand {
+    /// Expands in both directions.
+    Yes,
+    /// Does not expand.
+    No,
+    Horizontal,
+    Vertical,
+    /// Expands horizontally without affecting the natural size of the parent.
+    HorizontalFree,
+    /// Expands vertically without affecting the natural size of the parent.
+    VerticalFree,
+}
+
+impl FromStr for Expand {
+    type Err = ParseAttribError;
+    fn from_str(s: &str) -> Result<Expand, ParseAttribError> {
+        match &*s.trim().to_uppercase() {
+            "YES" => Ok(Expand::Yes),
+            "NO" => Ok(Expand::No),
+            "HORIZONTAL" => Ok(Expand::Horizontal),
+            "VERTICAL" => Ok(Expand::Vertical),
+            "HORIZONTALFREE" => Ok(Expand::HorizontalFree),
+            "VERTICALFREE" => Ok(Expand::VerticalFree),
+            _ => Err(ParseAttribError::new(s)),
+        }
+    }
+}
+
+impl fmt::Display for Expand {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(match *self {
+            Expand::Yes => "YES",
+            Expand::No => "NO",
+            Expand::Horizontal => "HORIZONTAL",
+            Expand::Vertical => "VERTICAL",
+            Expand::HorizontalFree => "HORIZONTALFREE",
+            Expand::VerticalFree => "VERTICALFREE",
+        })
+    }
+}
+
+impl From<bool> for Expand {
+    fn from(expand: bool) -> Expand {
+        if expand { Expand::Yes } else { Expand::No }
+    }
+}
+
+/// The alignment of elements, as in the ALIGNMENT attribute.
+///
+/// Horizontal alignments (`Left`, `Center`, `Right`) apply to the children of a `VBox` and
+/// vertical alignments (`Top`, `Center`, `Bottom`) apply to the children of a `HBox`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Alignment {
+    Left,
+    Center,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl FromStr for Alignment {
+    type Err = ParseAttribError;
+    fn from_str(s: &str) -> Result<Alignment, ParseAttribError> {
+        match &*s.trim().to_uppercase() {
+            "ALEFT" => Ok(Alignment::Left),
+            "ACENTER" => Ok(Alignment::Center),
+            "ARIGHT" => Ok(Alignment::Right),
+            "ATOP" => Ok(Alignment::Top),
+            "ABOTTOM" => Ok(Alignment::Bottom),
+            _ => Err(ParseAttribError::new(s)),
+        }
+    }
+}
+
+impl fmt::Display for Alignment {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(match *self {
+            Alignment::Left => "ALEFT",
+            Alignment::Center => "ACENTER",
+            Alignment::Right => "ARIGHT",
+            Alignment::Top => "ATOP",
+            Alignment::Bottom => "ABOTTOM",
+        })
+    }
+}
@@ -0,0 +1,148 @@
+//! Typed access to attributes.
+//!
+//! IUP elements are configured through named string attributes, which the binding exposes raw
+//! with `Element::set_attrib` and `Element::attrib`. On top of that each element has typed
+//! getters and setters for its most common attributes, e.g. `Widget::set_active` or
+//! `Button::set_title`, so that attribute names and values are checked at compile time.
+//!
+//! The raw API is still available for anything not covered by the typed methods.
+//!
+//! The typed methods convert values from and to the IUP string format with the `AttribValue`
+//! trait, which can also be used with `Element::attrib_value` and `Element::set_attrib_value`
+//! for any attribute.
+//!
+//! Attributes with a structured format have their own types in this module, such as `Size` or
+//! `Expand`, which implement `FromStr` and `Display` with the IUP format and thus can also be
+//! used with `Element::attrib_parse`.
+use std::error::Error;
+use std::fmt;
+
+pub mod size;
+pub mod layout;
+pub mod color;
+pub mod font;
+
+pub use self::size::{Size, RasterSize, Margin, Padding, Gap};
+pub use self::layout::{Expand, Alignment};
+pub use self::color::{Color, SystemColor};
+pub use self::font::Font;
+
+/// A value that can be converted from and to the string format of an IUP attribute.
+pub trait AttribValue : Sized {
+    /// Parses the value from the attribute string, `None` if the string is not valid.
+    fn from_attrib(s: &str) -> Option<Self>;
+    /// Formats the value as an attribute string.
+    fn to_attrib(&self) -> String;
+}
+
+impl AttribValue for String {
+    fn from_attrib(s: &str) -> Option<String> {
+        Some(s.to_owned())
+    }
+    fn to_attrib(&self) -> String {
+        self.clone()
+    }
+}
+
+/// Booleans are formatted as `YES` and `NO`, and parsed from either `YES`/`NO`, `ON`/`OFF`,
+/// `TRUE`/`FALSE` or `1`/`0` in any case.
+impl AttribValue for bool {
+    fn from_attrib(s: &str) -> Option<bool> {
+        match &*s.to_uppercase() {
+            "YES" | "ON" | "TRUE" | "1" => Some(true),
+            "NO" | "OFF" | "FALSE" | "0" => Some(false),
+            _ => None,
+        }
+    }
+    fn to_attrib(&self) -> String {
+        (if *self { "YES" } else { "NO" }).into()
+    }
+}
+
+macro_rules! impl_attrib_value_fromstr {
+    ($($ty:ty),*) => {
+        $(
+            impl AttribValue for $ty {
+                fn from_attrib(s: &str) -> Option<$ty> {
+                    s.trim().parse().ok()
+                }
+                fn to_attrib(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    }
+}
+
+impl_attrib_value_fromstr!(i32, u32, usize, f32, f64);
+impl_attrib_value_fromstr!(Size, RasterSize, Margin, Gap, Expand, Alignment, Color, Font);
+
+/// The error returned when a string is not a valid attribute value.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseAttribError {
+    value: String,
+}
+
+impl ParseAttribError {
+    #[doc(hidden)]
+    pub fn new<S: Into<String>>(value: S) -> ParseAttribError {
+        ParseAttribError { value: value.into() }
+    }
+}
+
+impl fmt::Display for ParseAttribError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "invalid attribute value `{}`", self.value)
+    }
+}
+
+impl Error for ParseAttribError {}
+
+/// Generates typed getter and setter pairs for attributes.
+///
+/// Each entry has the form `fn getter, setter: Type = "NAME";`, optionally preceded by doc
+/// comments and a visibility, and optionally followed by `, default expr` before the semicolon.
+///
+/// The getter returns `Option<Type>`, or `Type` with the default value when one is given. The
+/// setter takes any `Into<Type>` and returns `Self` like `Element::set_attrib`.
+///
+/// It can be used inside inherent impls (with `pub`) and inside traits bounded by `Element`.
+macro_rules! impl_attribs {
+    () => {};
+
+    (
+        $(#[$attr:meta])*
+        $vis:vis fn $getter:ident, $setter:ident: $ty:ty = $name:expr, default $default:expr;
+        $($rest:tt)*
+    ) => {
+        $(#[$attr])*
+        $vis fn $getter(&self) -> $ty {
+            $crate::Element::attrib_value(self, $name).unwrap_or($default)
+        }
+
+        $(#[$attr])*
+        $vis fn $setter<V: Into<$ty>>(&mut self, value: V) -> Self {
+            $crate::Element::set_attrib_value::<$ty, _>(self, $name, &value.into())
+        }
+
+        impl_attribs! { $($rest)* }
+    };
+
+    (
+        $(#[$attr:meta])*
+        $vis:vis fn $getter:ident, $setter:ident: $ty:ty = $name:expr;
+        $($rest:tt)*
+    ) => {
+        $(#[$attr])*
+        $vis fn $getter(&self) -> Option<$ty> {
+            $crate::Element::attrib_value(self, $name)
+        }
+
+        $(#[$attr])*
+        $vis fn $setter<V: Into<$ty>>(&mut self, value: V) -> Self {
+            $crate::Element::set_attrib_value::<$ty, _>(self, $name, &value.into())
+        }
+
+        impl_attribs! { $($rest)* }
+    };
+}
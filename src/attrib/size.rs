@@ -0,0 +1,165 @@
+//! Size, margin, padding and gap values.
+use std::fmt;
+use std::str::FromStr;
+
+use attrib::ParseAttribError;
+
+macro_rules! impl_size_type {
+    ($(#[$attr:meta])* pub struct $name:ident;) => {
+        $(#[$attr])*
+        ///
+        /// Either dimension may be `None`, in which case the natural size of the element is used
+        /// for it. The string format is `"WxH"`, where any of the numbers can be omitted,
+        /// e.g. `"100x"` or `"x50"`. With both dimensions `None` the string is empty.
+        #[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+        pub struct $name {
+            pub w: Option<u32>,
+            pub h: Option<u32>,
+        }
+
+        impl $name {
+            /// Creates a size with both dimensions set.
+            pub fn new(w: u32, h: u32) -> $name {
+                $name { w: Some(w), h: Some(h) }
+            }
+
+            /// Creates a size with only the width set.
+            pub fn width(w: u32) -> $name {
+                $name { w: Some(w), h: None }
+            }
+
+            /// Creates a size with only the height set.
+            pub fn height(h: u32) -> $name {
+                $name { w: None, h: Some(h) }
+            }
+        }
+
+        impl From<(u32, u32)> for $name {
+            fn from((w, h): (u32, u32)) -> $name {
+                $name::new(w, h)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseAttribError;
+            fn from_str(s: &str) -> Result<$name, ParseAttribError> {
+                let (w, h) = parse_pair(s)?;
+                Ok($name { w, h })
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                fmt_pair(fmt, self.w, self.h)
+            }
+        }
+    }
+}
+
+impl_size_type! {
+    /// The size of an element in character units, as in the SIZE attribute.
+    ///
+    /// A character unit is 1/4 of the average character width horizontally and 1/8 of the
+    /// character height vertically, for the font of the element.
+    pub struct Size;
+}
+
+impl_size_type! {
+    /// The size of an element in pixels, as in the RASTERSIZE attribute.
+    pub struct RasterSize;
+}
+
+/// A horizontal and vertical space, as in the MARGIN and PADDING attributes.
+///
+/// The string format is `"HxV"`, e.g. `"10x5"`. Depending on the attribute the values are
+/// in pixels (e.g. MARGIN) or in character units (e.g. CMARGIN, CPADDING).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub struct Margin {
+    pub horiz: u32,
+    pub vert: u32,
+}
+
+/// The space between the border and the contents of an element, see `Margin`.
+pub type Padding = Margin;
+
+impl Margin {
+    /// Creates a margin.
+    pub fn new(horiz: u32, vert: u32) -> Margin {
+        Margin { horiz, vert }
+    }
+
+    /// Creates a margin with the same horizontal and vertical values.
+    pub fn uniform(value: u32) -> Margin {
+        Margin::new(value, value)
+    }
+}
+
+impl From<(u32, u32)> for Margin {
+    fn from((horiz, vert): (u32, u32)) -> Margin {
+        Margin::new(horiz, vert)
+    }
+}
+
+impl FromStr for Margin {
+    type Err = ParseAttribError;
+    fn from_str(s: &str) -> Result<Margin, ParseAttribError> {
+        let (horiz, vert) = parse_pair(s)?;
+        Ok(Margin::new(horiz.unwrap_or(0), vert.unwrap_or(0)))
+    }
+}
+
+impl fmt::Display for Margin {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}x{}", self.horiz, self.vert)
+    }
+}
+
+/// A space between the children of a box, as in the GAP and CGAP attributes.
+///
+/// The string format is a single number, in pixels for GAP and in character units for CGAP.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct Gap(pub u32);
+
+impl From<u32> for Gap {
+    fn from(value: u32) -> Gap {
+        Gap(value)
+    }
+}
+
+impl FromStr for Gap {
+    type Err = ParseAttribError;
+    fn from_str(s: &str) -> Result<Gap, ParseAttribError> {
+        s.trim().parse().map(Gap).map_err(|_| ParseAttribError::new(s))
+    }
+}
+
+impl fmt::Display for Gap {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}", self.0)
+    }
+}
+
+/// Parses a `"AxB"` pair where any of the numbers may be omitted.
+fn parse_pair(s: &str) -> Result<(Option<u32>, Option<u32>), ParseAttribError> {
+    fn parse_part(part: &str) -> Result<Option<u32>, ParseAttribError> {
+        match part.trim() {
+            "" => Ok(None),
+            part => part.parse().map(Some).map_err(|_| ParseAttribError::new(part)),
+        }
+    }
+
+    let mut it = s.splitn(2, ['x', 'X']);
+    let a = parse_part(it.next().unwrap_or(""))?;
+    let b = parse_part(it.next().unwrap_or(""))?;
+    Ok((a, b))
+}
+
+fn fmt_pair(fmt: &mut fmt::Formatter, a: Option<u32>, b: Option<u32>) -> fmt::Result {
+    if a.is_none() && b.is_none() {
+        return Ok(());
+    }
+    if let Some(a) = a { write!(fmt, "{}", a)?; }
+    fmt.write_str("x")?;
+    if let Some(b) = b { write!(fmt, "{}", b)?; }
+    Ok(())
+}
@@ -1,7 +1,7 @@
 //! Mouse button presses callback.
 use iup_sys;
 use std::fmt;
-use libc::{c_char, c_int};
+use libc::{c_char, c_int, c_float};
 use callback::IntoRust;
 
 /// Mouse buttons.
@@ -180,3 +180,21 @@ impl_callback! {
     }
 }
 
+
+impl_callback! {
+    /// Action generated when the mouse wheel is rotated.
+    ///
+    /// The `f32` parameter is the amount the wheel was rotated in notches, positive when
+    /// rotated forward (away from the user) and negative otherwise.
+    ///
+    /// The `i32` parameters are the x,y position where the event has occurred, in pixels.
+    ///
+    /// The `KeyStates` parameter is the state of the mouse buttons and some keyboard keys at
+    /// the moment the event is generated.
+    pub trait WheelCb where Self: Element {
+        let name = "WHEEL_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, delta: c_float, x: c_int, y: c_int, status: *mut c_char) -> CallbackReturn;
+        fn set_wheel_cb<F: Callback(Self, f32, i32, i32, KeyStates)>(&mut self, cb: F) -> Self;
+        fn remove_wheel_cb(&mut self) -> Option<Box<_>>;
+    }
+}
@@ -209,6 +209,19 @@ impl_callback! {
     }
 }
 
+impl_callback! {
+    #[doc="Called when the value starts or ends to be interactively changed by the user."]
+    #[doc=""]
+    #[doc="The `bool` parameter is `true` when the change starts and `false` when it ends."]
+    #[doc="`ValueChangedCb` is still called for each intermediate value between both calls."]
+    pub trait ValueChangingCb where Self: Element {
+        let name = "VALUECHANGING_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, start: c_int) -> CallbackReturn;
+        fn set_valuechanging_cb<F: Callback(Self, bool)>(&mut self, cb: F) -> Self;
+        fn remove_valuechanging_cb(&mut self) -> Option<Box<_>>;
+    }
+}
+
 
 impl_callback! {
     #[doc="Action called when a file is *dropped* into the control."]
@@ -238,6 +251,34 @@ impl_callback! {
     }
 }
 
+impl_callback! {
+    #[doc="Action generated when the right mouse button is pressed over an item of the control."]
+    #[doc=""]
+    #[doc="The `i32` parameter identifies the item, see the specific control documentation."]
+    pub trait RightClickCb where Self: Element {
+        let name = "RIGHTCLICK_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, id: c_int) -> CallbackReturn;
+        fn set_rightclick_cb<F: Callback(Self, i32)>(&mut self, cb: F) -> Self;
+        fn remove_rightclick_cb(&mut self) -> Option<Box<_>>;
+    }
+}
+
+impl_callback! {
+    #[doc="Action generated when an internal drag and drop is executed, i.e. an item is dragged"]
+    #[doc="and dropped over another item of the same control."]
+    #[doc=""]
+    #[doc="The `i32` parameters identify the dragged item and the item it was dropped over, see the"]
+    #[doc="specific control documentation. The `bool` parameters are whether Shift and Ctrl were"]
+    #[doc="pressed, respectively."]
+    pub trait DragDropCb where Self: Element {
+        let name = "DRAGDROP_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, drag_id: c_int, drop_id: c_int,
+                           shift: c_int, control: c_int) -> CallbackReturn;
+        fn set_dragdrop_cb<F: Callback(Self, i32, i32, bool, bool)>(&mut self, cb: F) -> Self;
+        fn remove_dragdrop_cb(&mut self) -> Option<Box<_>>;
+    }
+}
+
 impl_callback! {
     #[doc="Called just before a dialog is closed when the user clicks the close button of the title bar"]
     #[doc="or an equivalent action."]
@@ -248,8 +289,11 @@ impl_callback! {
     pub trait CloseCb where Self: Element {
         let name = "CLOSE_CB";
         extern fn listener(ih: *mut iup_sys::Ihandle) -> CallbackReturn;
-        fn set_move_cb<F: Callback(Self)>(&mut self, cb: F) -> Self;
-        fn remove_move_cb(&mut self) -> Option<Box<_>>;
+        fn set_close_cb<F: Callback(Self)>(&mut self, cb: F) -> Self;
+        fn remove_close_cb(&mut self) -> Option<Box<_>>;
+
+        #[deprecated(note = "renamed to `set_close_cb` and `remove_close_cb`")]
+        fn set_move_cb, remove_move_cb;
     }
 }
 
@@ -269,7 +313,10 @@ impl_callback! {
     pub trait ResizeCb where Self: Element {
         let name = "RESIZE_CB";
         extern fn listener(ih: *mut iup_sys::Ihandle, w: c_int, h: c_int) -> CallbackReturn;
-        fn set_move_cb<F: Callback(Self, i32, i32)>(&mut self, cb: F) -> Self;
-        fn remove_move_cb(&mut self) -> Option<Box<_>>;
+        fn set_resize_cb<F: Callback(Self, i32, i32)>(&mut self, cb: F) -> Self;
+        fn remove_resize_cb(&mut self) -> Option<Box<_>>;
+
+        #[deprecated(note = "renamed to `set_resize_cb` and `remove_resize_cb`")]
+        fn set_move_cb, remove_move_cb;
     }
 }
@@ -0,0 +1,286 @@
+//! Keyboard callbacks and key codes.
+use std::fmt;
+use std::char;
+use libc::c_int;
+use callback::IntoRust;
+
+// Modifier flags and masks as defined in iupkey.h.
+const MOD_SHIFT: u32 = 0x1000_0000;
+const MOD_CTRL: u32 = 0x2000_0000;
+const MOD_ALT: u32 = 0x4000_0000;
+const MOD_SYS: u32 = 0x8000_0000;
+const BASE_MASK: u32 = 0x0FFF_FFFF;
+
+/// A keyboard key without its modifiers.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum KeyCode {
+    /// A printable character, including the space bar.
+    Char(char),
+    Backspace,
+    Tab,
+    LineFeed,
+    /// The Enter (Return) key.
+    Enter,
+    Esc,
+    Pause,
+    Home,
+    End,
+    Left,
+    Up,
+    Right,
+    Down,
+    PageUp,
+    PageDown,
+    /// The middle key of the numeric keypad (5 with NumLock off).
+    Middle,
+    Print,
+    Insert,
+    Menu,
+    Delete,
+    /// A function key, from `F(1)` to `F(12)`.
+    F(u8),
+    LShift,
+    RShift,
+    LCtrl,
+    RCtrl,
+    LAlt,
+    RAlt,
+    NumLock,
+    ScrollLock,
+    CapsLock,
+    /// Any other code not known by the binding.
+    Other(u32),
+}
+
+impl KeyCode {
+    fn from_raw(code: u32) -> KeyCode {
+        use self::KeyCode::*;
+        match code {
+            0x08 => Backspace,
+            0x09 => Tab,
+            0x0A => LineFeed,
+            0x0D => Enter,
+            0xFF13 => Pause,
+            0xFF14 => ScrollLock,
+            0xFF1B => Esc,
+            0xFF50 => Home,
+            0xFF51 => Left,
+            0xFF52 => Up,
+            0xFF53 => Right,
+            0xFF54 => Down,
+            0xFF55 => PageUp,
+            0xFF56 => PageDown,
+            0xFF57 => End,
+            0xFF0B => Middle,
+            0xFF61 => Print,
+            0xFF63 => Insert,
+            0xFF67 => Menu,
+            0xFF7F => NumLock,
+            0xFFBE..=0xFFC9 => F((code - 0xFFBE + 1) as u8),
+            0xFFE1 => LShift,
+            0xFFE2 => RShift,
+            0xFFE3 => LCtrl,
+            0xFFE4 => RCtrl,
+            0xFFE5 => CapsLock,
+            0xFFE9 => LAlt,
+            0xFFEA => RAlt,
+            0xFFFF => Delete,
+            _ => match char::from_u32(code) {
+                Some(c) if code < 0xFF00 && !c.is_control() => Char(c),
+                _ => Other(code),
+            },
+        }
+    }
+
+    fn to_raw(self) -> u32 {
+        use self::KeyCode::*;
+        match self {
+            Char(c) => c as u32,
+            Backspace => 0x08,
+            Tab => 0x09,
+            LineFeed => 0x0A,
+            Enter => 0x0D,
+            Pause => 0xFF13,
+            ScrollLock => 0xFF14,
+            Esc => 0xFF1B,
+            Home => 0xFF50,
+            Left => 0xFF51,
+            Up => 0xFF52,
+            Right => 0xFF53,
+            Down => 0xFF54,
+            PageUp => 0xFF55,
+            PageDown => 0xFF56,
+            End => 0xFF57,
+            Middle => 0xFF0B,
+            Print => 0xFF61,
+            Insert => 0xFF63,
+            Menu => 0xFF67,
+            NumLock => 0xFF7F,
+            F(n) => 0xFFBE + (n as u32).saturating_sub(1),
+            LShift => 0xFFE1,
+            RShift => 0xFFE2,
+            LCtrl => 0xFFE3,
+            RCtrl => 0xFFE4,
+            CapsLock => 0xFFE5,
+            LAlt => 0xFFE9,
+            RAlt => 0xFFEA,
+            Delete => 0xFFFF,
+            Other(code) => code,
+        }
+    }
+}
+
+impl fmt::Display for KeyCode {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        use self::KeyCode::*;
+        match *self {
+            Char(' ') => fmt.write_str("Space"),
+            Char(c) => write!(fmt, "{}", c),
+            F(n) => write!(fmt, "F{}", n),
+            PageUp => fmt.write_str("PgUp"),
+            PageDown => fmt.write_str("PgDn"),
+            Insert => fmt.write_str("Ins"),
+            Delete => fmt.write_str("Del"),
+            Other(code) => write!(fmt, "0x{:X}", code),
+            ref code => write!(fmt, "{:?}", code),
+        }
+    }
+}
+
+/// A key code together with the modifiers pressed along with it.
+///
+/// When combined with Ctrl, Alt or Sys, IUP always reports letters in uppercase, thus the
+/// constructors and the decoder of this type normalizes ASCII letters to uppercase in such
+/// combinations. This allows `Key::new(KeyCode::Char('s')).with_ctrl()` to match a Ctrl+S press.
+///
+/// See the [IUP Keyboard Codes][1] for details.
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/attrib/key.html
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Key {
+    pub code: KeyCode,
+    pub shift: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+    /// The system key, that is the *Windows key* in Windows and the *Apple key* in Mac.
+    pub sys: bool,
+}
+
+impl Key {
+    /// Creates a key with no modifiers.
+    pub fn new(code: KeyCode) -> Key {
+        Key { code, shift: false, ctrl: false, alt: false, sys: false }
+    }
+
+    /// Shortcut to `Key::new(KeyCode::Char(c)).with_ctrl()`.
+    pub fn ctrl(c: char) -> Key {
+        Key::new(KeyCode::Char(c)).with_ctrl()
+    }
+
+    /// Adds the Shift modifier to this key.
+    pub fn with_shift(self) -> Key {
+        Key { shift: true, ..self }
+    }
+
+    /// Adds the Ctrl modifier to this key.
+    pub fn with_ctrl(self) -> Key {
+        Key { ctrl: true, ..self }.normalized()
+    }
+
+    /// Adds the Alt modifier to this key.
+    pub fn with_alt(self) -> Key {
+        Key { alt: true, ..self }.normalized()
+    }
+
+    /// Adds the system key modifier to this key.
+    pub fn with_sys(self) -> Key {
+        Key { sys: true, ..self }.normalized()
+    }
+
+    /// Whether this key is a printable character with no modifier other than Shift.
+    pub fn is_printable(&self) -> bool {
+        match self.code {
+            KeyCode::Char(_) => !(self.ctrl || self.alt || self.sys),
+            _ => false,
+        }
+    }
+
+    /// Decodes a IUP key code.
+    #[doc(hidden)]
+    pub fn from_raw(c: c_int) -> Key {
+        let c = c as u32;
+        Key {
+            code: KeyCode::from_raw(c & BASE_MASK),
+            shift: c & MOD_SHIFT != 0,
+            ctrl: c & MOD_CTRL != 0,
+            alt: c & MOD_ALT != 0,
+            sys: c & MOD_SYS != 0,
+        }.normalized()
+    }
+
+    /// Encodes this key into a IUP key code.
+    #[doc(hidden)]
+    pub fn to_raw(&self) -> c_int {
+        let mut c = self.code.to_raw();
+        if self.shift { c |= MOD_SHIFT; }
+        if self.ctrl { c |= MOD_CTRL; }
+        if self.alt { c |= MOD_ALT; }
+        if self.sys { c |= MOD_SYS; }
+        c as c_int
+    }
+
+    fn normalized(self) -> Key {
+        match self.code {
+            KeyCode::Char(c) if self.ctrl || self.alt || self.sys => {
+                Key { code: KeyCode::Char(c.to_ascii_uppercase()), ..self }
+            },
+            _ => self,
+        }
+    }
+}
+
+impl From<KeyCode> for Key {
+    fn from(code: KeyCode) -> Key {
+        Key::new(code)
+    }
+}
+
+/// Formats the key the way it's usually displayed in menus, e.g. `Ctrl+Shift+S`.
+impl fmt::Display for Key {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        if self.ctrl { fmt.write_str("Ctrl+")?; }
+        if self.alt { fmt.write_str("Alt+")?; }
+        if self.sys { fmt.write_str("Sys+")?; }
+        if self.shift { fmt.write_str("Shift+")?; }
+        self.code.fmt(fmt)
+    }
+}
+
+impl IntoRust<Key> for c_int {
+    fn into_rust(self) -> Key {
+        Key::from_raw(self)
+    }
+}
+
+impl_callback! {
+    /// Action generated when a keyboard event occurs.
+    ///
+    /// The `Key` parameter is the key pressed together with its modifiers.
+    ///
+    /// The callback is called for the element with the keyboard focus. If it returns
+    /// `CallbackReturn::Continue` or the element has no such callback, the key is propagated
+    /// to the element's parent and so on up to the dialog.
+    ///
+    /// `CallbackReturn::Close` will be processed. If `CallbackReturn::Ignore` is returned
+    /// the key is ignored and not processed by the control nor propagated.
+    ///
+    /// On a `Dialog` the closure is called only for the keys with no shortcut, see
+    /// `Dialog::set_shortcut`.
+    ///
+    /// [Learn more](http://webserver2.tecgraf.puc-rio.br/iup/en/call/iup_k_any.html).
+    pub trait KAnyCb where Self: Element {
+        let name = "K_ANY";
+        extern fn listener(ih: *mut iup_sys::Ihandle, c: c_int) -> CallbackReturn;
+        fn set_k_any<F: Callback(Self, Key)>(&mut self, cb: F) -> Self;
+        fn remove_k_any(&mut self) -> Option<Box<_>>;
+    }
+}
@@ -94,6 +94,9 @@ macro_rules! get_fbox_callback {
 ///   + `resolve_args` is optional should have a code body, and is also not defined.
 ///      It is responsible for translating the C arguments into Rust arguments. By default it just
 ///      calls the `IntoRust` trait for each argument.
+///   + The old names of renamed methods are optional, given as `fn old_set, old_remove;` after
+///     `$remove_method` with at least a `#[deprecated]` attribute. They forward to the methods
+///     with the current names.
 ///
 /// **Note**: Don't forget to add a dropper for the event in `drop_callbacks` after using this
 /// macro. You **must** do so to free allocations associated with closures.
@@ -110,6 +113,10 @@ macro_rules! impl_callback {
 
             fn $set_method:ident<F: Callback(Self $(, $fn_arg_ty:ty)*)>(&mut self, cb: F) -> Self;
             fn $remove_method:ident(&mut self) -> Option<Box<_>>;
+            $(
+                $(#[$old_attr:meta])+
+                fn $old_set_method:ident, $old_remove_method:ident;
+            )?
         }
 
     ) => {
@@ -121,6 +128,10 @@ macro_rules! impl_callback {
 
                 fn $set_method<F: Callback(Self $(, $fn_arg_ty)*)>(&mut self, cb: F) -> Self;
                 fn $remove_method(&mut self) -> Option<Box<_>>;
+                $(
+                    $(#[$old_attr])+
+                    fn $old_set_method, $old_remove_method;
+                )?
 
                 fn resolve_args(elem: Self, $($ls_arg: $ls_arg_ty),*) -> (Self, $($fn_arg_ty),*) {
                     (elem, $($ls_arg.into_rust()),*)
@@ -139,6 +150,10 @@ macro_rules! impl_callback {
 
             fn $set_method:ident<F: Callback(Self $(, $fn_arg_ty:ty)*)>(&mut self, cb: F) -> Self;
             fn $remove_method:ident(&mut self) -> Option<Box<_>>;
+            $(
+                $(#[$old_attr:meta])+
+                fn $old_set_method:ident, $old_remove_method:ident;
+            )?
 
             fn resolve_args($aa_argself:ident: Self, $($aa_arg:ident: $aa_arg_ty:ty),*)
                             -> (Self, $($aa_ret_ty:ty),*)
@@ -186,6 +201,30 @@ macro_rules! impl_callback {
                     old_cb
                 }
             }
+
+            impl_callback! {
+                @old_names $set_method, $remove_method, ($($fn_arg_ty),*),
+                [$($(#[$old_attr])+ fn $old_set_method, $old_remove_method;)?]
+            }
+        }
+    };
+
+    // Old names of the methods of an element callback, forwarding to the current ones.
+    (@old_names $set_method:ident, $remove_method:ident, ($($fn_arg_ty:ty),*), []) => {};
+    (
+        @old_names $set_method:ident, $remove_method:ident, ($($fn_arg_ty:ty),*),
+        [$(#[$old_attr:meta])+ fn $old_set_method:ident, $old_remove_method:ident;]
+    ) => {
+        $(#[$old_attr])+
+        fn $old_set_method<F>(&mut self, cb: F) -> Self
+                where F: $crate::callback::Callback<(Self, $($fn_arg_ty),*)> {
+            self.$set_method(cb)
+        }
+
+        $(#[$old_attr])+
+        fn $old_remove_method(&mut self)
+                            -> Option<Box<dyn $crate::callback::Callback<(Self, $($fn_arg_ty),*)>>> {
+            self.$remove_method()
         }
     };
 
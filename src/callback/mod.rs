@@ -1,16 +1,19 @@
 //! Event-driven communication.
 
 use iup_sys;
-use libc::{c_char, c_int};
+use libc::{c_char, c_int, c_float};
 use std::path::PathBuf;
 use std::char;
 
+use element::{Element, Handle};
+
 #[macro_use]
 mod macros;
 pub mod callbacks;
 pub use self::callbacks::*;
 
 pub mod button;
+pub mod key;
 
 // This is called right when a IUP element is being destroyed and it should free up all data
 // associated with callbacks. Just use the `drop_callback!` macro for each callback implemented.
@@ -21,6 +24,10 @@ pub unsafe fn drop_callbacks(ih: *mut iup_sys::Ihandle) {
     // button.rs
     drop_callback!(ih, "BUTTON_CB");
     drop_callback!(ih, "MOTION_CB");
+    drop_callback!(ih, "WHEEL_CB");
+
+    // key.rs
+    drop_callback!(ih, "K_ANY");
 
     // callbacks.rs
     drop_callback!(ih, "ACTION");
@@ -36,7 +43,10 @@ pub unsafe fn drop_callbacks(ih: *mut iup_sys::Ihandle) {
     drop_callback!(ih, "CARET_CB");
     drop_callback!(ih, "SPIN_CB");
     drop_callback!(ih, "VALUECHANGED_CB");
+    drop_callback!(ih, "VALUECHANGING_CB");
     drop_callback!(ih, "DROPFILES_CB");
+    drop_callback!(ih, "RIGHTCLICK_CB");
+    drop_callback!(ih, "DRAGDROP_CB");
     drop_callback!(ih, "CLOSE_CB");
     drop_callback!(ih, "MOVE_CB");
     drop_callback!(ih, "RESIZE_CB");
@@ -46,6 +56,46 @@ pub unsafe fn drop_callbacks(ih: *mut iup_sys::Ihandle) {
     drop_callback!(ih, "MDIACTIVATE_CB");
     drop_callback!(ih, "SHOW_CB");
     drop_callback!(ih, "TRAYCLICK_CB");
+    drop_callback!(ih, "SHORTCUTS");
+
+    // dialog/file.rs
+    drop_callback!(ih, "FILE_CB");
+
+    // thread.rs
+    drop_callback!(ih, "POSTMESSAGE_CB");
+
+    // dialog/progress.rs
+    drop_callback!(ih, "CANCEL_CB");
+
+    // dialog/color.rs
+    drop_callback!(ih, "COLORUPDATE_CB");
+
+    // canvas.rs
+    drop_callback!(ih, "SCROLL_CB");
+
+    // menu.rs
+    drop_callback!(ih, "HIGHLIGHT_CB");
+    drop_callback!(ih, "OPEN_CB");
+    drop_callback!(ih, "MENUCLOSE_CB");
+
+    // list.rs
+    drop_callback!(ih, "DBLCLICK_CB");
+    drop_callback!(ih, "MULTISELECT_CB");
+    drop_callback!(ih, "EDIT_CB");
+    drop_callback!(ih, "DROPDOWN_CB");
+
+    // tabs.rs
+    drop_callback!(ih, "TABCHANGE_CB");
+    drop_callback!(ih, "TABCHANGEPOS_CB");
+    drop_callback!(ih, "TABCLOSE_CB");
+
+    // tree.rs
+    drop_callback!(ih, "SELECTION_CB");
+    drop_callback!(ih, "MULTISELECTION_CB");
+    drop_callback!(ih, "BRANCHOPEN_CB");
+    drop_callback!(ih, "BRANCHCLOSE_CB");
+    drop_callback!(ih, "EXECUTELEAF_CB");
+    drop_callback!(ih, "RENAME_CB");
 }
 
 
@@ -134,6 +184,12 @@ impl IntoRust<usize> for c_int {
     }
 }
 
+impl IntoRust<f32> for c_float {
+    fn into_rust(self) -> f32 {
+        self as f32
+    }
+}
+
 impl IntoRust<bool> for c_int {
     fn into_rust(self) -> bool {
         self != 0
@@ -152,6 +208,24 @@ impl IntoRust<String> for *const c_char {
     }
 }
 
+impl IntoRust<Handle> for *mut iup_sys::Ihandle {
+    fn into_rust(self) -> Handle {
+        Handle::from_raw(self)
+    }
+}
+
+impl IntoRust<Option<Handle>> for *mut iup_sys::Ihandle {
+    fn into_rust(self) -> Option<Handle> {
+        if self.is_null() { None } else { Some(Handle::from_raw(self)) }
+    }
+}
+
+impl IntoRust<Option<usize>> for c_int {
+    fn into_rust(self) -> Option<usize> {
+        if self < 0 { None } else { Some(self as usize) }
+    }
+}
+
 impl IntoRust<Option<char>> for c_int {
     fn into_rust(self) -> Option<char> {
         if self == 0 { None } else { Some(char::from_u32(self as u32).unwrap()) }
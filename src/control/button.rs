@@ -3,6 +3,7 @@ use std::ptr;
 use std::ffi::CString;
 
 use Element;
+use attrib::Padding;
 
 /// See the [IUP Button Documentation][1].
 /// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupbutton.html
@@ -21,6 +22,20 @@ impl Button {
     }
 
     // TODO with_image
+
+    impl_attribs! {
+        /// The text of the button. It may contain a mnemonic, see `menu::Item`.
+        pub fn title, set_title: String = "TITLE";
+
+        /// Whether the button border is shown only when the mouse is over it. Default `false`.
+        pub fn flat, set_flat: bool = "FLAT", default false;
+
+        /// The space between the border and the contents of the button, in pixels.
+        pub fn padding, set_padding: Padding = "PADDING";
+
+        /// The space between the border and the contents of the button, in character units.
+        pub fn cpadding, set_cpadding: Padding = "CPADDING";
+    }
 }
 
 impl_widget!(Button, "button");
@@ -31,7 +46,7 @@ impl ::callback::KillFocusCb for Button {}
 impl ::callback::EnterWindowCb for Button {}
 impl ::callback::LeaveWindowCb for Button {}
 impl ::callback::HelpCb for Button {}
-// TODO impl K_ callbacks when it's implemented.
+impl ::callback::key::KAnyCb for Button {}
 
 /// Action generated when the button 1 (usually left) is selected.
 ///
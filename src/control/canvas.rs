@@ -0,0 +1,242 @@
+use iup_sys;
+use libc::{c_int, c_float};
+use std::ptr;
+
+use Element;
+use callback::IntoRust;
+use draw::DrawContext;
+
+/// A working area that can be used to display graphics and custom-drawn widgets.
+///
+/// Drawing is usually performed during the `CanvasAction` (redraw) callback. Every time the
+/// canvas needs to be repainted the callback is called with the current scrollbar positions.
+///
+/// See the [IUP Canvas Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupcanvas.html
+pub struct Canvas(*mut iup_sys::Ihandle);
+
+impl Canvas {
+    /// Creates a canvas.
+    pub fn new() -> Canvas {
+        unsafe { Canvas::from_raw(iup_sys::IupCanvas(ptr::null())) }
+    }
+
+    /// Begins drawing on the canvas, see the `draw` module for details.
+    pub fn draw<'a>(&'a mut self) -> DrawContext<'a> {
+        DrawContext::new(self)
+    }
+
+    /// Gets the size of the drawing area in pixels, excluding scrollbars and borders.
+    ///
+    /// Returns `None` if the canvas is not mapped.
+    pub fn draw_size(&self) -> Option<(i32, i32)> {
+        self.attrib("DRAWSIZE").and_then(|size| {
+            let mut it = size.splitn(2, 'x').map(|v| v.parse::<i32>().ok());
+            match (it.next(), it.next()) {
+                (Some(Some(w)), Some(Some(h))) => Some((w, h)),
+                _ => None,
+            }
+        })
+    }
+
+    impl_attribs! {
+        /// The position of the horizontal scrollbar thumb, between `xmin` and `xmax - dx`.
+        pub fn posx, set_posx: f64 = "POSX", default 0.0;
+
+        /// The position of the vertical scrollbar thumb, between `ymin` and `ymax - dy`.
+        pub fn posy, set_posy: f64 = "POSY", default 0.0;
+
+        /// The size of the horizontal scrollbar thumb, i.e. the visible width of the document.
+        ///
+        /// When `dx` is greater or equal than `xmax - xmin` the scrollbar is hidden or disabled
+        /// (see `XAUTOHIDE`).
+        pub fn dx, set_dx: f64 = "DX", default 0.1;
+
+        /// The size of the vertical scrollbar thumb, i.e. the visible height of the document.
+        ///
+        /// When `dy` is greater or equal than `ymax - ymin` the scrollbar is hidden or disabled
+        /// (see `YAUTOHIDE`).
+        pub fn dy, set_dy: f64 = "DY", default 0.1;
+    }
+
+    /// Gets which scrollbars are enabled in the canvas.
+    pub fn scrollbar(&self) -> ScrollBar {
+        match self.attrib("SCROLLBAR") {
+            Some(ref s) if s == "YES" => ScrollBar::Both,
+            Some(ref s) if s == "HORIZONTAL" => ScrollBar::Horizontal,
+            Some(ref s) if s == "VERTICAL" => ScrollBar::Vertical,
+            _ => ScrollBar::None,
+        }
+    }
+
+    /// Enables the scrollbars of the canvas. Can be set only before the canvas is mapped.
+    pub fn set_scrollbar(&mut self, scrollbar: ScrollBar) -> Self {
+        self.set_attrib("SCROLLBAR", scrollbar.as_str())
+    }
+
+    /// Sets the range of the horizontal scrollbar. Default `0.0..1.0`.
+    pub fn set_xrange(&mut self, xmin: f64, xmax: f64) -> Self {
+        self.set_attrib("XMIN", xmin.to_string());
+        self.set_attrib("XMAX", xmax.to_string())
+    }
+
+    /// Gets the range of the horizontal scrollbar.
+    pub fn xrange(&self) -> (f64, f64) {
+        (self.attrib_parse("XMIN").unwrap_or(0.0), self.attrib_parse("XMAX").unwrap_or(1.0))
+    }
+
+    /// Sets the range of the vertical scrollbar. Default `0.0..1.0`.
+    pub fn set_yrange(&mut self, ymin: f64, ymax: f64) -> Self {
+        self.set_attrib("YMIN", ymin.to_string());
+        self.set_attrib("YMAX", ymax.to_string())
+    }
+
+    /// Gets the range of the vertical scrollbar.
+    pub fn yrange(&self) -> (f64, f64) {
+        (self.attrib_parse("YMIN").unwrap_or(0.0), self.attrib_parse("YMAX").unwrap_or(1.0))
+    }
+
+    /// Sets the amount the thumb moves when the horizontal and vertical scrollbar arrows
+    /// are pressed. Default is `dx/10` and `dy/10`.
+    pub fn set_line(&mut self, linex: f64, liney: f64) -> Self {
+        self.set_attrib("LINEX", linex.to_string());
+        self.set_attrib("LINEY", liney.to_string())
+    }
+}
+
+impl_widget!(Canvas, "canvas");
+impl ::callback::MapCb for Canvas {}
+impl ::callback::UnmapCb for Canvas {}
+impl ::callback::GetFocusCb for Canvas {}
+impl ::callback::KillFocusCb for Canvas {}
+impl ::callback::EnterWindowCb for Canvas {}
+impl ::callback::LeaveWindowCb for Canvas {}
+impl ::callback::HelpCb for Canvas {}
+impl ::callback::key::KAnyCb for Canvas {}
+
+/// Action generated when any mouse button is pressed or released inside the canvas.
+impl ::callback::button::ButtonCb for Canvas {}
+
+/// Action generated when the mouse is moved over the canvas.
+impl ::callback::button::MotionCb for Canvas {}
+
+/// Action generated when the mouse wheel is rotated over the canvas.
+///
+/// If this callback is not defined the wheel will automatically scroll the canvas in the
+/// vertical direction by some lines, and the `ScrollCb` callback will be called if defined.
+impl ::callback::button::WheelCb for Canvas {}
+
+/// Action generated when the canvas size is changed.
+///
+/// The parameters are the same as `Canvas::draw_size`.
+///
+/// This action is also generated when the canvas is mapped, before the first redraw.
+impl ::callback::ResizeCb for Canvas {}
+
+/// Action generated when one or more files are dropped in the canvas.
+impl ::callback::DropFilesCb for Canvas {}
+
+/// See the `ScrollCb` documentation.
+impl self::ScrollCb for Canvas {}
+
+/// See the `CanvasAction` documentation.
+impl self::CanvasAction for Canvas {}
+
+impl_callback! {
+    #[doc="Action generated when the canvas needs to be redrawn."]
+    #[doc=""]
+    #[doc="The `f32` parameters are the thumb positions of the horizontal and vertical scrollbars,"]
+    #[doc="the same as `Canvas::posx` and `Canvas::posy`."]
+    pub trait CanvasAction where Self: Element {
+        let name = "ACTION";
+        extern fn listener(ih: *mut iup_sys::Ihandle, posx: c_float, posy: c_float) -> CallbackReturn;
+        fn set_action<F: Callback(Self, f32, f32)>(&mut self, cb: F) -> Self;
+        fn remove_action(&mut self) -> Option<Box<_>>;
+    }
+}
+
+impl_callback! {
+    #[doc="Called when some manipulation is made to the scrollbar."]
+    #[doc=""]
+    #[doc="The `ScrollOp` parameter indicates the operation performed on the scrollbar and the"]
+    #[doc="`f32` parameters are the new thumb positions of the horizontal and vertical scrollbars."]
+    #[doc=""]
+    #[doc="The canvas is automatically redrawn only if this callback is **not** defined."]
+    pub trait ScrollCb where Self: Element {
+        let name = "SCROLL_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, op: c_int, posx: c_float, posy: c_float) -> CallbackReturn;
+        fn set_scroll_cb<F: Callback(Self, ScrollOp, f32, f32)>(&mut self, cb: F) -> Self;
+        fn remove_scroll_cb(&mut self) -> Option<Box<_>>;
+    }
+}
+
+/// The scrollbars enabled in a canvas.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ScrollBar {
+    None,
+    Horizontal,
+    Vertical,
+    Both,
+}
+
+impl ScrollBar {
+    fn as_str(self) -> &'static str {
+        match self {
+            ScrollBar::None => "NO",
+            ScrollBar::Horizontal => "HORIZONTAL",
+            ScrollBar::Vertical => "VERTICAL",
+            ScrollBar::Both => "YES",
+        }
+    }
+}
+
+/// The operation performed on a scrollbar, see `ScrollCb`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ScrollOp {
+    /// Line up.
+    LineUp,
+    /// Line down.
+    LineDown,
+    /// Page up.
+    PageUp,
+    /// Page down.
+    PageDown,
+    /// Vertical thumb positioned.
+    PosV,
+    /// Vertical thumb being dragged.
+    DragV,
+    /// Column left.
+    LineLeft,
+    /// Column right.
+    LineRight,
+    /// Page left.
+    PageLeft,
+    /// Page right.
+    PageRight,
+    /// Horizontal thumb positioned.
+    PosH,
+    /// Horizontal thumb being dragged.
+    DragH,
+    /// An operation unknown to this binding, with its IUP code.
+    Other(i32),
+}
+
+impl IntoRust<ScrollOp> for c_int {
+    fn into_rust(self) -> ScrollOp {
+        match self {
+            iup_sys::IUP_SBUP => ScrollOp::LineUp,
+            iup_sys::IUP_SBDN => ScrollOp::LineDown,
+            iup_sys::IUP_SBPGUP => ScrollOp::PageUp,
+            iup_sys::IUP_SBPGDN => ScrollOp::PageDown,
+            iup_sys::IUP_SBPOSV => ScrollOp::PosV,
+            iup_sys::IUP_SBDRAGV => ScrollOp::DragV,
+            iup_sys::IUP_SBLEFT => ScrollOp::LineLeft,
+            iup_sys::IUP_SBRIGHT => ScrollOp::LineRight,
+            iup_sys::IUP_SBPGLEFT => ScrollOp::PageLeft,
+            iup_sys::IUP_SBPGRIGHT => ScrollOp::PageRight,
+            iup_sys::IUP_SBPOSH => ScrollOp::PosH,
+            iup_sys::IUP_SBDRAGH => ScrollOp::DragH,
+            op => ScrollOp::Other(op),
+        }
+    }
+}
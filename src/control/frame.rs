@@ -3,6 +3,11 @@ use std::ptr;
 
 use Element;
 
+/// A container that draws a border, with an optional title, around its child.
+///
+/// The frame has no margin, gap or alignment of its own, to space or align the child put it in
+/// a `VBox` or `HBox` and use the layout attributes of the box.
+///
 /// See the [IUP Frame Documentation][1].
 /// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupframe.html
 pub struct Frame(*mut iup_sys::Ihandle);
@@ -17,6 +22,11 @@ impl Frame {
     pub fn new_empty() -> Frame {
         unsafe { Frame::from_raw(iup_sys::IupFrame(ptr::null_mut())) }
     }
+
+    impl_attribs! {
+        /// The text shown in the frame border.
+        pub fn title, set_title: String = "TITLE";
+    }
 }
 
 impl_widget_container!(Frame, "frame");
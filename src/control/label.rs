@@ -27,6 +27,17 @@ impl Label {
         let clabel = CString::new(label.into()).unwrap();
         unsafe { Label::from_raw(iup_sys::IupLabel(clabel.as_ptr())) }
     }
+
+    impl_attribs! {
+        /// The text of the label.
+        pub fn title, set_title: String = "TITLE";
+
+        /// Whether the text is wrapped to fit the label width. Default `false`.
+        pub fn wordwrap, set_wordwrap: bool = "WORDWRAP", default false;
+
+        /// Whether a text that does not fit the label is shortened with an ellipsis. Default `false`.
+        pub fn ellipsis, set_ellipsis: bool = "ELLIPSIS", default false;
+    }
 }
 
 impl_widget!(Label, "label");
@@ -4,7 +4,13 @@ use std::ptr;
 
 use Element;
 use callback::IntoRust;
+use image::ImageElement;
 
+/// An element that displays a list of items, in a box or in a dropdown, optionally with an
+/// edit box for text input.
+///
+/// Items are identified by their id, the position in the list starting at 1.
+///
 /// See the [IUP List Documentation][1].
 /// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iuplist.html
 pub struct List(*mut iup_sys::Ihandle);
@@ -40,15 +46,152 @@ impl List {
     ///
     /// # Panics
     /// Panics if id is less than 1.
-    pub fn item<A>(&self, id: u32) -> Option<String> {
+    pub fn item(&self, id: u32) -> Option<String> {
         assert!(id > 0);
         self.attrib(id.to_string())
     }
 
+    /// Changes the text of the item at the specified id (starts from 1).
+    ///
+    /// # Panics
+    /// Panics if id is less than 1.
+    pub fn set_item<S: Into<String>>(&mut self, id: u32, text: S) -> Self {
+        assert!(id > 0);
+        self.set_attrib(id.to_string(), text)
+    }
+
+    /// Adds an item to the end of the list. Ignored if called before being mapped.
+    pub fn append_item<S: Into<String>>(&mut self, text: S) -> Self {
+        self.set_attrib("APPENDITEM", text)
+    }
+
+    /// Inserts an item before the item at the specified id (starts from 1). Ignored if called
+    /// before being mapped.
+    ///
+    /// Using `count() + 1` as the id appends the item.
+    ///
+    /// # Panics
+    /// Panics if id is less than 1.
+    pub fn insert_item<S: Into<String>>(&mut self, id: u32, text: S) -> Self {
+        assert!(id > 0);
+        self.set_attrib(format!("INSERTITEM{}", id), text)
+    }
+
+    /// Removes the item at the specified id (starts from 1). Ignored if called before being
+    /// mapped.
+    ///
+    /// # Panics
+    /// Panics if id is less than 1.
+    pub fn remove_item(&mut self, id: u32) -> Self {
+        assert!(id > 0);
+        self.set_attrib("REMOVEITEM", id.to_string())
+    }
+
     /// Clears the list of items. Ignored if called before being mapped.
     pub fn clear(&mut self) -> Self {
         self.set_attrib("REMOVEITEM", "ALL")
     }
+
+    /// Gets the number of items.
+    pub fn count(&self) -> u32 {
+        self.attrib_parse("COUNT").unwrap_or(0)
+    }
+
+    impl_attribs! {
+        /// Whether more than one item can be selected. Default `false`.
+        ///
+        /// Can only be set before the list is mapped, and is ignored for dropdowns and lists
+        /// with an edit box.
+        pub fn multiple, set_multiple: bool = "MULTIPLE", default false;
+
+        /// Whether the items are sorted in alphabetical order. Default `false`.
+        ///
+        /// Can only be set before the list is mapped. When enabled inserted items are appended
+        /// and sorted.
+        pub fn sort, set_sort: bool = "SORT", default false;
+
+        /// Whether items can be moved with the mouse. Default `false`. See `DragDropCb`.
+        pub fn show_dragdrop, set_show_dragdrop: bool = "SHOWDRAGDROP", default false;
+
+        /// Whether the items show images, see `List::set_item_image`. Default `false`.
+        ///
+        /// Can only be set before the list is mapped.
+        ///
+        /// \[Windows and GTK Only\]
+        pub fn show_image, set_show_image: bool = "SHOWIMAGE", default false;
+    }
+
+    /// Gets the value of the list according to its kind, see `ListValue`.
+    pub fn value(&self) -> ListValue {
+        let value = self.attrib("VALUE").unwrap_or_default();
+        if self.attrib_bool("EDITBOX").unwrap_or(false) {
+            ListValue::Text(value)
+        } else if self.multiple() {
+            ListValue::Multiple(value.chars().enumerate()
+                                     .filter(|&(_, c)| c == '+')
+                                     .map(|(i, _)| i as u32 + 1)
+                                     .collect())
+        } else {
+            ListValue::Single(value.trim().parse().ok().filter(|&id| id != 0))
+        }
+    }
+
+    /// Selects the item at the specified id (starts from 1), or clears the selection if `None`.
+    ///
+    /// For lists with multiple selection the other items are deselected.
+    pub fn set_selected(&mut self, id: Option<u32>) -> Self {
+        if self.multiple() {
+            self.set_selection(id.into_iter().collect::<Vec<_>>())
+        } else {
+            self.set_attrib("VALUE", id.unwrap_or(0).to_string())
+        }
+    }
+
+    /// Selects the items at the specified ids (starting from 1) of a list with multiple
+    /// selection, deselecting the others.
+    pub fn set_selection<A>(&mut self, ids: A) -> Self where A: AsRef<[u32]> {
+        let mut value = vec![b'-'; self.count() as usize];
+        for &id in ids.as_ref() {
+            if id > 0 && id as usize <= value.len() {
+                value[id as usize - 1] = b'+';
+            }
+        }
+        self.set_attrib("VALUE", String::from_utf8(value).unwrap())
+    }
+
+    /// Changes the text of the edit box.
+    pub fn set_edit_text<S: Into<String>>(&mut self, text: S) -> Self {
+        self.set_attrib("VALUE", text)
+    }
+
+    /// Scrolls the list so the item at the specified id (starts from 1) is at the top.
+    pub fn set_top_item(&mut self, id: u32) -> Self {
+        self.set_attrib("TOPITEM", id.to_string())
+    }
+
+    /// Sets the image shown next to the item at the specified id (starts from 1).
+    ///
+    /// Images are shown only when SHOWIMAGE is enabled before the list is mapped.
+    ///
+    /// \[Windows and GTK Only\]
+    ///
+    /// # Panics
+    /// Panics if id is less than 1.
+    pub fn set_item_image<I: ImageElement>(&mut self, id: u32, image: I) -> Self {
+        assert!(id > 0);
+        self.set_attrib_handle(format!("IMAGE{}", id), image)
+    }
+}
+
+/// The value of a `List`, which depends on the kind of the list.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ListValue {
+    /// The id of the selected item of a list with single selection, if any.
+    Single(Option<u32>),
+    /// The ids of the selected items of a list with multiple selection.
+    Multiple(Vec<u32>),
+    /// The text of the edit box of a list with an edit box.
+    Text(String),
 }
 
 impl_widget!(List, "list");
@@ -63,7 +206,7 @@ impl ::callback::KillFocusCb for List {}
 impl ::callback::EnterWindowCb for List {}
 impl ::callback::LeaveWindowCb for List {}
 impl ::callback::HelpCb for List {}
-// TODO impl K_ callbacks when it's implemented.
+impl ::callback::key::KAnyCb for List {}
 
 // TODO impl future DragSource and DragTarget traits.
 
@@ -95,12 +238,90 @@ impl ::callback::button::MotionCb for List {}
 /// changed or when the text is edited.
 impl ::callback::ValueChangedCb for List {}
 
-// TODO:
-// DBLCLICK_CB
-// MULTISELECT_CB
-// EDIT_CB
-// DROPDOWN_CB
-// DRAGDROP_CB
+/// Action generated when an item is dropped over another item, when SHOWDRAGDROP is enabled.
+///
+/// The `i32` parameters are the id of the dragged item and the id of the item it was dropped
+/// over.
+///
+/// If the callback returns `CallbackReturn::Continue` the item is moved to the new position,
+/// or copied if Ctrl was pressed.
+impl ::callback::DragDropCb for List {}
+
+/// See the `DblClickCb` documentation.
+impl self::DblClickCb for List {}
+
+/// See the `MultiSelectCb` documentation.
+impl self::MultiSelectCb for List {}
+
+/// See the `EditCb` documentation.
+impl self::EditCb for List {}
+
+/// See the `DropDownCb` documentation.
+impl self::DropDownCb for List {}
+
+impl_callback! {
+    #[doc="Action generated when the user double clicks an item. Called only when DROPDOWN=NO."]
+    #[doc=""]
+    #[doc="The `u32` parameter is the id of the item starting at 1 and the `String` is its text."]
+    pub trait DblClickCb where Self: Element {
+        let name = "DBLCLICK_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, item: c_int, text: *const c_char)
+                                                                        -> CallbackReturn;
+        fn set_dblclick_cb<F: Callback(Self, u32, String)>(&mut self, cb: F) -> Self;
+        fn remove_dblclick_cb(&mut self) -> Option<Box<_>>;
+    }
+}
+
+impl_callback! {
+    #[doc="Action generated when the selection of a list with multiple selection changes."]
+    #[doc=""]
+    #[doc="The parameter has the id of each changed item, starting at 1, and its new state."]
+    #[doc=""]
+    #[doc="When defined `ListAction` is not called for lists with multiple selection."]
+    pub trait MultiSelectCb where Self: Element {
+        let name = "MULTISELECT_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, value: *const c_char) -> CallbackReturn;
+        fn set_multiselect_cb<F: Callback(Self, Vec<(u32, ListItemState)>)>(&mut self, cb: F)
+                                                                                    -> Self;
+        fn remove_multiselect_cb(&mut self) -> Option<Box<_>>;
+
+        fn resolve_args(elem: Self, value: *const c_char) -> (Self, Vec<(u32, ListItemState)>) {
+            let value = string_from_cstr!(value);
+            (elem, value.chars().enumerate().filter_map(|(i, c)| match c {
+                '+' => Some((i as u32 + 1, ListItemState::Selected)),
+                '-' => Some((i as u32 + 1, ListItemState::Deselected)),
+                _ => None,
+            }).collect())
+        }
+    }
+}
+
+impl_callback! {
+    #[doc="Action generated when the text of the edit box is edited, but before its value is"]
+    #[doc="actually changed. Valid only when EDITBOX=YES."]
+    #[doc=""]
+    #[doc="The parameters and returned values are just like in `TextAction`."]
+    pub trait EditCb where Self: Element {
+        let name = "EDIT_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, c: c_int, newvalue: *const c_char)
+                                                                        -> CallbackReturn;
+        fn set_edit_cb<F: Callback(Self, Option<char>, String)>(&mut self, cb: F) -> Self;
+        fn remove_edit_cb(&mut self) -> Option<Box<_>>;
+    }
+}
+
+impl_callback! {
+    #[doc="Action generated when the dropdown of the list is shown or hidden. Valid only when"]
+    #[doc="DROPDOWN=YES."]
+    #[doc=""]
+    #[doc="The `bool` parameter is whether the dropdown is shown."]
+    pub trait DropDownCb where Self: Element {
+        let name = "DROPDOWN_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, state: c_int) -> CallbackReturn;
+        fn set_dropdown_cb<F: Callback(Self, bool)>(&mut self, cb: F) -> Self;
+        fn remove_dropdown_cb(&mut self) -> Option<Box<_>>;
+    }
+}
 
 /// See the `ListAction` documentation.
 impl self::ListAction for List {}
@@ -124,6 +345,7 @@ impl_callback! {
     }
 }
 
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum ListItemState {
     Deselected,
     Selected,
@@ -0,0 +1,201 @@
+//! Lists populated from a data model.
+//!
+//! A `ListBinding` fills a `List` with the items of a `ListModel` and keeps them in sync as the
+//! model changes. After changing the model the binding must be told what changed, so only the
+//! affected items are updated:
+//!
+//! ```ignore
+//! let list = List::new();
+//! let mut binding = ListBinding::new(list, vec!["Apple", "Banana"]);
+//!
+//! binding.model_mut().push("Cherry");
+//! binding.notify(ListChange::Inserted(2));
+//!
+//! let selected = binding.selected(); // Index in the model, e.g. `Some(2)`.
+//! ```
+use iup_sys;
+use std::fmt::Display;
+
+use Element;
+use element::Handle;
+use control::{List, ListValue};
+
+/// A source of items for a `List`, see `ListBinding`.
+///
+/// Items are indexed from 0, unlike the ids of the `List` items which start at 1.
+pub trait ListModel {
+    /// Gets the number of items.
+    fn len(&self) -> usize;
+
+    /// Checks whether there are no items.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the text of the item at the specified index.
+    fn text(&self, index: usize) -> String;
+
+    /// Gets the image of the item at the specified index, if any. Default `None`.
+    ///
+    /// The image must outlive the list, e.g. an image named with `Element::add_handle_name`
+    /// and found with `Handle::from_named`. See also `List::set_item_image`.
+    fn image(&self, _index: usize) -> Option<Handle> {
+        None
+    }
+}
+
+impl<T: Display> ListModel for Vec<T> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn text(&self, index: usize) -> String {
+        self[index].to_string()
+    }
+}
+
+/// A change in a `ListModel`, with the index of the changed item.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ListChange {
+    /// An item was inserted at the index, the following items moved down.
+    Inserted(usize),
+    /// The item at the index was removed, the following items moved up.
+    Removed(usize),
+    /// The item at the index changed.
+    Updated(usize),
+    /// The whole model changed, all the items are replaced.
+    Reset,
+}
+
+/// Keeps the items of a `List` in sync with a `ListModel`.
+///
+/// The list items must only be changed through the binding, and the list must not be sorted
+/// (`List::set_sort`) so the ids of the items keep matching the model indices.
+pub struct ListBinding<M: ListModel> {
+    list: List,
+    model: M,
+}
+
+impl<M: ListModel> ListBinding<M> {
+    /// Binds the list to the model, replacing the items of the list with the model items.
+    pub fn new(list: List, model: M) -> ListBinding<M> {
+        let mut binding = ListBinding { list, model };
+        binding.reset();
+        binding
+    }
+
+    /// Gets the bound list.
+    pub fn list(&self) -> List {
+        self.list
+    }
+
+    /// Gets the model.
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    /// Gets the model to change it, the changes must then be reported with `notify`.
+    pub fn model_mut(&mut self) -> &mut M {
+        &mut self.model
+    }
+
+    /// Unbinds the list, returning the model. The list items are kept.
+    pub fn into_model(self) -> M {
+        self.model
+    }
+
+    /// Updates the list after a change in the model.
+    ///
+    /// Before the list is mapped IUP ignores the insertion and removal of single items, so
+    /// until then every change replaces all the list items, as `ListChange::Reset`.
+    ///
+    /// # Panics
+    /// Panics if the index of an inserted or updated item is out of the model bounds.
+    pub fn notify(&mut self, change: ListChange) {
+        if !self.is_mapped() {
+            return self.reset();
+        }
+        match change {
+            ListChange::Inserted(index) => {
+                self.list.insert_item(to_id(index), self.model.text(index));
+                self.update_image(index);
+            },
+            ListChange::Removed(index) => {
+                self.list.remove_item(to_id(index));
+            },
+            ListChange::Updated(index) => {
+                self.list.set_item(to_id(index), self.model.text(index));
+                self.update_image(index);
+            },
+            ListChange::Reset => self.reset(),
+        }
+    }
+
+    /// Gets the index of the selected item, if any.
+    ///
+    /// For lists with multiple selection the first selected item is returned.
+    pub fn selected(&self) -> Option<usize> {
+        self.selection().into_iter().next()
+    }
+
+    /// Gets the indices of the selected items.
+    ///
+    /// Lists with an edit box have no selected items.
+    pub fn selection(&self) -> Vec<usize> {
+        match self.list.value() {
+            ListValue::Single(id) => id.into_iter().map(to_index).collect(),
+            ListValue::Multiple(ids) => ids.into_iter().map(to_index).collect(),
+            ListValue::Text(_) => Vec::new(),
+        }
+    }
+
+    /// Selects the item at the specified index, or clears the selection if `None`.
+    pub fn set_selected(&mut self, index: Option<usize>) {
+        self.list.set_selected(index.map(to_id));
+    }
+
+    /// Selects the items at the specified indices of a list with multiple selection.
+    pub fn set_selection<A>(&mut self, indices: A) where A: AsRef<[usize]> {
+        let ids = indices.as_ref().iter().map(|&index| to_id(index)).collect::<Vec<_>>();
+        self.list.set_selection(ids);
+    }
+
+    /// Converts the id of a list item, as given by the list callbacks, to a model index.
+    ///
+    /// Returns `None` for the id 0, which is not an item.
+    pub fn index(id: u32) -> Option<usize> {
+        if id == 0 { None } else { Some(to_index(id)) }
+    }
+
+    fn is_mapped(&self) -> bool {
+        !unsafe { iup_sys::IupGetAttribute(self.list.raw(), cstr!("WID")) }.is_null()
+    }
+
+    /// Replaces all the list items with the model items.
+    fn reset(&mut self) {
+        let items = (0..self.model.len()).map(|index| self.model.text(index)).collect::<Vec<_>>();
+        self.list.set_items(&items);
+        // Before the list is mapped `List::clear` is ignored, unsetting the item after the last
+        // removes any previous items.
+        self.list.clear_attrib(to_id(items.len()).to_string());
+        for index in 0..items.len() {
+            self.update_image(index);
+        }
+    }
+
+    fn update_image(&mut self, index: usize) {
+        let id = to_id(index);
+        match self.model.image(index) {
+            Some(image) => self.list.set_attrib_handle(format!("IMAGE{}", id), image),
+            None => self.list.clear_attrib(format!("IMAGE{}", id)),
+        };
+    }
+}
+
+fn to_id(index: usize) -> u32 {
+    index as u32 + 1
+}
+
+fn to_index(id: u32) -> usize {
+    id as usize - 1
+}
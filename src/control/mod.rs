@@ -8,6 +8,12 @@ pub mod progress;
 pub mod toggle;
 pub mod frame;
 pub mod list;
+pub mod canvas;
+pub mod tree;
+pub mod val;
+pub mod spin;
+pub mod textformat;
+pub mod listmodel;
 
 pub use self::text::{Text, TextAction};
 pub use self::label::Label;
@@ -15,4 +21,12 @@ pub use self::button::Button;
 pub use self::progress::ProgressBar;
 pub use self::toggle::{Toggle, ToggleAction};
 pub use self::frame::Frame;
-pub use self::list::{List, ListAction};
+pub use self::list::{List, ListValue, ListItemState, ListAction};
+pub use self::list::{DblClickCb, MultiSelectCb, EditCb, DropDownCb};
+pub use self::listmodel::{ListModel, ListBinding, ListChange};
+pub use self::canvas::{Canvas, CanvasAction, ScrollBar, ScrollOp, ScrollCb};
+pub use self::val::{Val, TicksPos};
+pub use self::spin::{SpinText, SpinBox, SpinAlign};
+pub use self::textformat::{TextFormat, FontWeight, TextAlignment};
+pub use self::tree::{Tree, NodeId, NodeKind, NodeState};
+pub use self::tree::{SelectionCb, MultiSelectionCb, BranchOpenCb, BranchCloseCb, ExecuteLeafCb, RenameCb};
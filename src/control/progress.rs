@@ -2,6 +2,9 @@ use iup_sys;
 
 use Element;
 
+/// A control that shows the progress of an operation, as a value between a minimum and a
+/// maximum.
+///
 /// See the [IUP Progress Bar Documentation][1].
 /// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupprogressbar.html
 pub struct ProgressBar(*mut iup_sys::Ihandle);
@@ -11,6 +14,23 @@ impl ProgressBar {
     pub fn new() -> ProgressBar {
         unsafe { ProgressBar::from_raw(iup_sys::IupProgressBar()) } 
     }
+
+    impl_attribs! {
+        /// The current progress, between `min` and `max`. Default `0.0`.
+        pub fn value, set_value: f64 = "VALUE", default 0.0;
+
+        /// The value of no progress. Default `0.0`.
+        pub fn min, set_min: f64 = "MIN", default 0.0;
+
+        /// The value of complete progress. Default `1.0`.
+        pub fn max, set_max: f64 = "MAX", default 1.0;
+
+        /// Shows an animation of undefined progress instead of the value. Default `false`.
+        pub fn marquee, set_marquee: bool = "MARQUEE", default false;
+
+        /// Shows the progress as dashes instead of a continuous bar. Default `false`.
+        pub fn dashed, set_dashed: bool = "DASHED", default false;
+    }
 }
 
 impl_widget!(ProgressBar, "progressbar");
@@ -0,0 +1,131 @@
+use iup_sys;
+use std::ptr;
+
+use Element;
+
+/// A text field to enter an integer, with buttons to increment and decrement it.
+///
+/// It's a `Text` with the SPIN attribute set, with typed access to the spin attributes.
+///
+/// The spin attributes of IUP are integers only, there's no native spin for real values. For
+/// real values use a `SpinBox` around a `Text`, updating the text in the `SpinCb`, or a `Val`.
+///
+/// See the [IUP Text Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iuptext.html
+pub struct SpinText(*mut iup_sys::Ihandle);
+
+impl SpinText {
+    /// Creates a spin text field with the default range of 0 to 100.
+    pub fn new() -> SpinText {
+        unsafe { SpinText::from_raw(iup_sys::IupText(ptr::null_mut())) }
+            .set_attrib("SPIN", "YES")
+    }
+
+    /// Creates a spin text field with the specified range, the value starts at `min`.
+    pub fn with_range(min: i32, max: i32) -> SpinText {
+        SpinText::new().set_min(min).set_max(max).set_value(min)
+    }
+
+    impl_attribs! {
+        /// The current value. Default `0`.
+        pub fn value, set_value: i32 = "SPINVALUE", default 0;
+
+        /// The minimum value. Default `0`.
+        pub fn min, set_min: i32 = "SPINMIN", default 0;
+
+        /// The maximum value. Default `100`.
+        pub fn max, set_max: i32 = "SPINMAX", default 100;
+
+        /// The increment of the buttons. Default `1`.
+        pub fn step, set_step: i32 = "SPININC", default 1;
+
+        /// Whether the value wraps around when passing the limits. Default `false`.
+        pub fn wrap, set_wrap: bool = "SPINWRAP", default false;
+
+        /// Whether the value is updated automatically by the buttons. Default `true`.
+        ///
+        /// When `false` the value must be updated in the `SpinCb`.
+        pub fn auto, set_auto: bool = "SPINAUTO", default true;
+    }
+
+    /// Gets the side of the text where the buttons are.
+    ///
+    /// \[GTK and Motif Only\]
+    pub fn align(&self) -> SpinAlign {
+        match self.attrib("SPINALIGN") {
+            Some(ref s) if s == "LEFT" => SpinAlign::Left,
+            _ => SpinAlign::Right,
+        }
+    }
+
+    /// Sets the side of the text where the buttons are. Default `SpinAlign::Right`.
+    ///
+    /// Can only be set before the control is mapped.
+    ///
+    /// \[GTK and Motif Only\]
+    pub fn set_align(&mut self, align: SpinAlign) -> Self {
+        self.set_attrib("SPINALIGN", match align {
+            SpinAlign::Left => "LEFT",
+            SpinAlign::Right => "RIGHT",
+        })
+    }
+}
+
+// The classname tells it apart from a plain `Text` when downcasting a `Handle`.
+impl_widget!(SpinText, "text:SPIN");
+impl ::callback::MapCb for SpinText {}
+impl ::callback::UnmapCb for SpinText {}
+impl ::callback::GetFocusCb for SpinText {}
+impl ::callback::KillFocusCb for SpinText {}
+impl ::callback::EnterWindowCb for SpinText {}
+impl ::callback::LeaveWindowCb for SpinText {}
+impl ::callback::HelpCb for SpinText {}
+impl ::callback::key::KAnyCb for SpinText {}
+
+/// Action generated when a spin button is pressed.
+///
+/// The `i32` parameter is the value after incremented.
+///
+/// May return `CallbackReturn::Ignore` but that is only allowed on Windows and Motif.
+impl ::callback::SpinCb for SpinText {}
+
+/// Called after the value was interactively changed by the user.
+impl ::callback::ValueChangedCb for SpinText {}
+
+/// The side of a `SpinText` where the buttons are.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SpinAlign {
+    Left,
+    Right,
+}
+
+
+/// A container that adds spin buttons next to its child.
+///
+/// Unlike `SpinText` the buttons don't change anything by themselves, the `SpinCb` must
+/// update the child.
+///
+/// See the [IUP SpinBox Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupspin.html
+pub struct SpinBox(*mut iup_sys::Ihandle);
+
+impl SpinBox {
+    /// Creates a spin box around the specified child.
+    pub fn new<E: Element>(child: E) -> SpinBox {
+        unsafe {
+            let ih = iup_sys::IupCreate(cstr!("spinbox"));
+            iup_sys::IupAppend(ih, child.raw());
+            SpinBox::from_raw(ih)
+        }
+    }
+}
+
+impl_widget_container!(SpinBox, "spinbox");
+impl ::callback::MapCb for SpinBox {}
+impl ::callback::UnmapCb for SpinBox {}
+
+/// Action generated when a spin button is pressed.
+///
+/// The `i32` parameter is `1` when the increment button is pressed and `-1` when the
+/// decrement button is pressed.
+impl ::callback::SpinCb for SpinBox {}
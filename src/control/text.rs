@@ -3,6 +3,23 @@ use libc::{c_char, c_int};
 use std::{ptr};
 
 use Element;
+use control::TextFormat;
+
+/// Masks for `Text::set_mask` that restrict the text to numbers.
+pub mod mask {
+    use iup_sys;
+
+    /// An integer, with an optional sign.
+    pub const INT: &str = iup_sys::IUP_MASK_INT;
+    /// An unsigned integer.
+    pub const UINT: &str = iup_sys::IUP_MASK_UINT;
+    /// A real number, with an optional sign.
+    pub const FLOAT: &str = iup_sys::IUP_MASK_FLOAT;
+    /// An unsigned real number.
+    pub const UFLOAT: &str = iup_sys::IUP_MASK_UFLOAT;
+    /// A real number with an optional exponent, e.g. `1.5e-3`.
+    pub const EFLOAT: &str = iup_sys::IUP_MASK_EFLOAT;
+}
 
 /// See the [IUP Text Documentation][1].
 /// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iuptext.html
@@ -16,11 +33,170 @@ impl Text {
 
     /// Creates a spin control.
     ///
-    /// The spin increments and decrements an integer number. 
+    /// The spin increments and decrements an integer number. See `SpinText` for typed access
+    /// to the spin attributes.
     pub fn new_spin() -> Text {
         Text::new().set_attrib_data("SPIN", cstr!("YES") as *const _)
     }
 
+    /// Creates a editable text-field with multiple lines.
+    pub fn new_multiline() -> Text {
+        Text::new().set_attrib("MULTILINE", "YES")
+    }
+
+    impl_attribs! {
+        /// The text of the control.
+        pub fn value, set_value: String = "VALUE";
+
+        /// Whether the user is prevented from editing the text. Default `false`.
+        pub fn readonly, set_readonly: bool = "READONLY", default false;
+
+        /// Whether the text is hidden with a symbol for each character. Default `false`.
+        ///
+        /// Can only be set before the control is mapped.
+        pub fn password, set_password: bool = "PASSWORD", default false;
+
+        /// The maximum number of characters the user can enter.
+        pub fn nc, set_nc: usize = "NC";
+
+        /// The selected text, setting it replaces the selection.
+        pub fn selected_text, set_selected_text: String = "SELECTEDTEXT";
+
+        /// The position of the caret, starting at 0.
+        pub fn caret_pos, set_caret_pos: usize = "CARETPOS", default 0;
+
+        /// Whether the text of a multiline control can be formatted with `Text::add_format`.
+        /// Default `false`.
+        ///
+        /// Can only be set before the control is mapped.
+        pub fn formatting, set_formatting: bool = "FORMATTING", default false;
+    }
+
+    /// Applies a format to a range of the text, see `TextFormat`.
+    ///
+    /// Formatting must be enabled with `Text::set_formatting`.
+    pub fn add_format(&mut self, format: &TextFormat) -> Self {
+        self.set_attrib_data("ADDFORMATTAG_HANDLE", format.to_tag() as *const _)
+    }
+
+    /// Appends a text to the end of the value.
+    ///
+    /// In multiline controls the text is added in a new line, unless APPENDNEWLINE is `NO`.
+    pub fn append<S: Into<String>>(&mut self, text: S) -> Self {
+        self.set_attrib("APPEND", text)
+    }
+
+    /// Inserts a text at the caret position, replacing the selection if any.
+    pub fn insert<S: Into<String>>(&mut self, text: S) -> Self {
+        self.set_attrib("INSERT", text)
+    }
+
+    /// Gets the selection as start and end positions, the end exclusive, or `None` if there's
+    /// no selection.
+    pub fn selection_pos(&self) -> Option<(usize, usize)> {
+        self.attrib("SELECTIONPOS").and_then(|s| {
+            let mut it = s.splitn(2, ':').map(|pos| pos.trim().parse().ok());
+            match (it.next(), it.next()) {
+                (Some(Some(start)), Some(Some(end))) => Some((start, end)),
+                _ => None,
+            }
+        })
+    }
+
+    /// Selects the text between the start and end positions, the end exclusive.
+    pub fn set_selection_pos(&mut self, start: usize, end: usize) -> Self {
+        self.set_attrib("SELECTIONPOS", format!("{}:{}", start, end))
+    }
+
+    /// Gets the selection as start and end `(lin, col)`, the end exclusive, or `None` if
+    /// there's no selection. See `convert_pos_to_lincol`.
+    pub fn selection(&self) -> Option<((i32, i32), (i32, i32))> {
+        self.attrib("SELECTION").and_then(|s| {
+            let mut it = s.splitn(2, ':').map(parse_lincol);
+            match (it.next(), it.next()) {
+                (Some(Some(start)), Some(Some(end))) => Some((start, end)),
+                _ => None,
+            }
+        })
+    }
+
+    /// Selects the text between the start and end `(lin, col)`, the end exclusive.
+    pub fn set_selection(&mut self, start: (i32, i32), end: (i32, i32)) -> Self {
+        self.set_attrib("SELECTION", format!("{},{}:{},{}", start.0, start.1, end.0, end.1))
+    }
+
+    /// Selects all the text.
+    pub fn select_all(&mut self) -> Self {
+        self.set_attrib("SELECTION", "ALL")
+    }
+
+    /// Removes the selection, without changing the text.
+    pub fn select_none(&mut self) -> Self {
+        self.set_attrib("SELECTION", "NONE")
+    }
+
+    /// Gets the caret position as `(lin, col)`, both starting at 1.
+    pub fn caret(&self) -> (i32, i32) {
+        self.attrib("CARET").and_then(|s| parse_lincol(&s)).unwrap_or((1, 1))
+    }
+
+    /// Moves the caret to the `(lin, col)` position, both starting at 1.
+    pub fn set_caret(&mut self, lin: i32, col: i32) -> Self {
+        self.set_attrib("CARET", format!("{},{}", lin, col))
+    }
+
+    /// Restricts the text to the specified pattern, e.g. one of the `mask` constants.
+    ///
+    /// See the [IUP Mask Documentation][1] for the pattern syntax.
+    /// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/attrib/iup_mask.html
+    pub fn set_mask<S: Into<String>>(&mut self, mask: S) -> Self {
+        self.set_attrib("MASK", mask)
+    }
+
+    /// Removes the mask of the text.
+    pub fn clear_mask(&mut self) -> Self {
+        self.clear_attrib("MASK")
+    }
+
+    /// Scrolls the text so the position is visible.
+    pub fn scroll_to_pos(&mut self, pos: usize) -> Self {
+        self.set_attrib("SCROLLTOPOS", pos.to_string())
+    }
+
+    /// Copies the selection to the clipboard.
+    pub fn copy(&mut self) -> Self {
+        self.set_attrib("CLIPBOARD", "COPY")
+    }
+
+    /// Copies the selection to the clipboard and removes it.
+    pub fn cut(&mut self) -> Self {
+        self.set_attrib("CLIPBOARD", "CUT")
+    }
+
+    /// Replaces the selection with the text in the clipboard.
+    pub fn paste(&mut self) -> Self {
+        self.set_attrib("CLIPBOARD", "PASTE")
+    }
+
+    /// Removes the selection.
+    pub fn clear_selection(&mut self) -> Self {
+        self.set_attrib("CLIPBOARD", "CLEAR")
+    }
+
+    /// Undoes the last change.
+    ///
+    /// \[Windows and GTK Only\]
+    pub fn undo(&mut self) -> Self {
+        self.set_attrib("CLIPBOARD", "UNDO")
+    }
+
+    /// Redoes the last undone change.
+    ///
+    /// \[Windows and GTK Only\]
+    pub fn redo(&mut self) -> Self {
+        self.set_attrib("CLIPBOARD", "REDO")
+    }
+
     /// Converts a (lin, col) character positioning into an absolute position.
     ///
     /// lin and col starts at 1, pos starts at 0. For single line controls pos is always *col-1*.
@@ -47,6 +223,16 @@ impl Text {
 
 impl_widget!(Text, "text");
 
+/// Parses a `"lin,col"` position, or a `"col"` position of a single line control.
+fn parse_lincol(s: &str) -> Option<(i32, i32)> {
+    let mut it = s.splitn(2, ',').map(|v| v.trim().parse().ok());
+    match (it.next(), it.next()) {
+        (Some(Some(lin)), Some(Some(col))) => Some((lin, col)),
+        (Some(Some(col)), None) => Some((1, col)),
+        _ => None,
+    }
+}
+
 /// Returns a position in the string.
 impl ::element::ConvertXYToPos for Text {}
 
@@ -57,7 +243,7 @@ impl ::callback::KillFocusCb for Text {}
 impl ::callback::EnterWindowCb for Text {}
 impl ::callback::LeaveWindowCb for Text {}
 impl ::callback::HelpCb for Text {}
-// TODO impl K_ callbacks when it's implemented.
+impl ::callback::key::KAnyCb for Text {}
 
 // TODO impl future DragSource and DragTarget traits.
 
@@ -0,0 +1,188 @@
+//! Formatting of ranges of a multiline `Text`.
+use iup_sys;
+
+use Element;
+use attrib::{Color, Font};
+
+/// A format applied to a range of a multiline `Text` with `Text::add_format`.
+///
+/// Formatting must be enabled in the text control with `Text::set_formatting` before it's
+/// mapped. The range defaults to the current selection.
+///
+/// ```ignore
+/// let text = Text::new_multiline().set_formatting(true);
+/// // ...
+/// text.add_format(&TextFormat::new().selection_pos(0, 5)
+///                                   .fgcolor((255, 0, 0))
+///                                   .weight(FontWeight::Bold));
+/// ```
+///
+/// See the [IUP Text Formatting Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/attrib/iup_formatting.html
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct TextFormat {
+    attribs: Vec<(&'static str, String)>,
+}
+
+impl TextFormat {
+    /// Creates an empty format applying to the current selection.
+    pub fn new() -> TextFormat {
+        TextFormat { attribs: vec![] }
+    }
+
+    fn with<S: Into<String>>(mut self, name: &'static str, value: S) -> TextFormat {
+        self.attribs.retain(|&(attrib, _)| attrib != name);
+        self.attribs.push((name, value.into()));
+        self
+    }
+
+    /// Applies the format to the text between the start and end positions, the end exclusive.
+    pub fn selection_pos(self, start: usize, end: usize) -> TextFormat {
+        self.with("SELECTIONPOS", format!("{}:{}", start, end))
+    }
+
+    /// Applies the format to the text between the start and end `(lin, col)`, the end
+    /// exclusive.
+    pub fn selection(self, start: (i32, i32), end: (i32, i32)) -> TextFormat {
+        self.with("SELECTION", format!("{},{}:{},{}", start.0, start.1, end.0, end.1))
+    }
+
+    /// Applies the format to all the text.
+    pub fn all(self) -> TextFormat {
+        self.with("SELECTION", "ALL")
+    }
+
+    /// Sets the text color. The alpha of the color is ignored.
+    pub fn fgcolor<C: Into<Color>>(self, color: C) -> TextFormat {
+        self.with("FGCOLOR", color.into().opaque().to_string())
+    }
+
+    /// Sets the background color. The alpha of the color is ignored.
+    pub fn bgcolor<C: Into<Color>>(self, color: C) -> TextFormat {
+        self.with("BGCOLOR", color.into().opaque().to_string())
+    }
+
+    /// Sets the font face, size and style.
+    pub fn font(self, font: &Font) -> TextFormat {
+        self.font_face(&*font.face)
+            .font_size(font.size)
+            .weight(if font.bold { FontWeight::Bold } else { FontWeight::Normal })
+            .italic(font.italic)
+            .underline(font.underline)
+            .strikeout(font.strikeout)
+    }
+
+    /// Sets the font face, e.g. `"Courier"`.
+    pub fn font_face<S: Into<String>>(self, face: S) -> TextFormat {
+        self.with("FONTFACE", face)
+    }
+
+    /// Sets the font size in points, or in pixels when negative.
+    pub fn font_size(self, size: i32) -> TextFormat {
+        self.with("FONTSIZE", size.to_string())
+    }
+
+    /// Sets the font weight.
+    pub fn weight(self, weight: FontWeight) -> TextFormat {
+        self.with("WEIGHT", weight.as_str())
+    }
+
+    /// Makes the text italic or not.
+    pub fn italic(self, italic: bool) -> TextFormat {
+        self.with("ITALIC", if italic { "YES" } else { "NO" })
+    }
+
+    /// Underlines the text or not.
+    pub fn underline(self, underline: bool) -> TextFormat {
+        self.with("UNDERLINE", if underline { "SINGLE" } else { "NONE" })
+    }
+
+    /// Strikes out the text or not.
+    pub fn strikeout(self, strikeout: bool) -> TextFormat {
+        self.with("STRIKEOUT", if strikeout { "YES" } else { "NO" })
+    }
+
+    /// Sets the alignment of the paragraphs.
+    pub fn alignment(self, alignment: TextAlignment) -> TextFormat {
+        self.with("ALIGNMENT", alignment.as_str())
+    }
+
+    /// Sets the indentation of the paragraphs in pixels.
+    pub fn indent(self, indent: u32) -> TextFormat {
+        self.with("INDENT", indent.to_string())
+    }
+
+    /// Sets the indentation of the first line of the paragraphs in pixels, relative to the
+    /// paragraph indentation.
+    ///
+    /// \[Windows Only\]
+    pub fn indent_first(self, indent: i32) -> TextFormat {
+        self.with("INDENTFIRST", indent.to_string())
+    }
+
+    /// Sets the right indentation of the paragraphs in pixels.
+    pub fn indent_right(self, indent: u32) -> TextFormat {
+        self.with("INDENTRIGHT", indent.to_string())
+    }
+
+    /// Creates the IUP format tag, which is destroyed by the text control once added.
+    pub(crate) fn to_tag(&self) -> *mut iup_sys::Ihandle {
+        let mut tag = unsafe { FormatTag::from_raw(iup_sys::IupUser()) };
+        for &(name, ref value) in &self.attribs {
+            tag.set_attrib(name, value.clone());
+        }
+        tag.raw()
+    }
+}
+
+/// The IUP element holding the attributes of a format.
+struct FormatTag(*mut iup_sys::Ihandle);
+
+impl_element!(FormatTag, "user");
+
+/// The weight of a font in a `TextFormat`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FontWeight {
+    ExtraLight,
+    Light,
+    Normal,
+    SemiBold,
+    Bold,
+    ExtraBold,
+    Heavy,
+}
+
+impl FontWeight {
+    fn as_str(self) -> &'static str {
+        match self {
+            FontWeight::ExtraLight => "EXTRALIGHT",
+            FontWeight::Light => "LIGHT",
+            FontWeight::Normal => "NORMAL",
+            FontWeight::SemiBold => "SEMIBOLD",
+            FontWeight::Bold => "BOLD",
+            FontWeight::ExtraBold => "EXTRABOLD",
+            FontWeight::Heavy => "HEAVY",
+        }
+    }
+}
+
+/// The alignment of paragraphs in a `TextFormat`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TextAlignment {
+    Left,
+    Right,
+    Center,
+    /// \[Windows Only\]
+    Justify,
+}
+
+impl TextAlignment {
+    fn as_str(self) -> &'static str {
+        match self {
+            TextAlignment::Left => "LEFT",
+            TextAlignment::Right => "RIGHT",
+            TextAlignment::Center => "CENTER",
+            TextAlignment::Justify => "JUSTIFY",
+        }
+    }
+}
@@ -19,6 +19,14 @@ impl Toggle {
         let ctitle = CString::new(title.into()).unwrap();
         unsafe { Toggle::from_raw(iup_sys::IupToggle(ctitle.as_ptr(), ptr::null_mut())) }
     }
+
+    impl_attribs! {
+        /// The text of the toggle. It may contain a mnemonic, see `menu::Item`.
+        pub fn title, set_title: String = "TITLE";
+
+        /// Whether the toggle is on. Default `false`.
+        pub fn value, set_value: bool = "VALUE", default false;
+    }
 }
 
 impl_widget!(Toggle, "toggle");
@@ -29,7 +37,7 @@ impl ::callback::KillFocusCb for Toggle {}
 impl ::callback::EnterWindowCb for Toggle {}
 impl ::callback::LeaveWindowCb for Toggle {}
 impl ::callback::HelpCb for Toggle {}
-// TODO impl K_ callbacks when it's implemented.
+impl ::callback::key::KAnyCb for Toggle {}
 
 /// Called after the value was interactively changed by the user.
 ///
@@ -0,0 +1,442 @@
+use iup_sys;
+use libc::{c_char, c_int, c_void};
+use std::slice;
+
+use Element;
+use image::ImageElement;
+use attrib::Color;
+use callback::IntoRust;
+
+/// A tree containing nodes of branches or leaves.
+///
+/// Each node is identified by a `NodeId`, which is the position of the node in the tree counting
+/// from the root (id 0) in depth-first order. Thus the id of a node changes when nodes are added,
+/// removed or moved before it. To track a node across such changes associate some user data to it
+/// (`Tree::set_userdata`) and later find it with `Tree::find_userdata`.
+///
+/// By default the tree starts with a single root branch, see `Tree::set_add_root`.
+///
+/// See the [IUP Tree Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iuptree.html
+pub struct Tree(*mut iup_sys::Ihandle);
+
+/// The identifier of a node in a `Tree`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct NodeId(pub i32);
+
+impl NodeId {
+    /// The root node of the tree.
+    pub const ROOT: NodeId = NodeId(0);
+
+    /// Used as the reference node to add the first node of an empty tree.
+    pub const NONE: NodeId = NodeId(-1);
+}
+
+impl IntoRust<NodeId> for c_int {
+    fn into_rust(self) -> NodeId {
+        NodeId(self)
+    }
+}
+
+/// Whether a node is a branch or a leaf.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NodeKind {
+    Branch,
+    Leaf,
+}
+
+/// Whether a branch is expanded, showing its children, or collapsed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NodeState {
+    Expanded,
+    Collapsed,
+}
+
+impl Tree {
+    /// Creates a tree.
+    pub fn new() -> Tree {
+        unsafe { Tree::from_raw(iup_sys::IupTree()) }
+    }
+
+    impl_attribs! {
+        /// Whether the tree should start with a root branch. Default `true`.
+        ///
+        /// Can be set only before the tree is mapped.
+        pub fn add_root, set_add_root: bool = "ADDROOT", default true;
+
+        /// Allows the user to rename nodes with F2 or a double click. Default `false`.
+        ///
+        /// See also `RenameCb`.
+        pub fn show_rename, set_show_rename: bool = "SHOWRENAME", default false;
+
+        /// Allows the user to move nodes by dragging them. Default `false`.
+        ///
+        /// See also `DragDropCb`.
+        pub fn show_dragdrop, set_show_dragdrop: bool = "SHOWDRAGDROP", default false;
+    }
+
+    /// Adds a new branch as the first child of `parent`, returning the id of the new node.
+    ///
+    /// If `parent` is a leaf the node is added after it at the same depth. Use `NodeId::NONE`
+    /// as `parent` to add the first node of an empty tree.
+    ///
+    /// Ignored if called before the tree is mapped.
+    pub fn add_branch<S: Into<String>>(&mut self, parent: NodeId, title: S) -> Option<NodeId> {
+        self.set_attrib(format!("ADDBRANCH{}", parent.0), title);
+        self.last_added()
+    }
+
+    /// Adds a new leaf as the first child of `parent`, returning the id of the new node.
+    ///
+    /// Follows the same rules of `Tree::add_branch`.
+    pub fn add_leaf<S: Into<String>>(&mut self, parent: NodeId, title: S) -> Option<NodeId> {
+        self.set_attrib(format!("ADDLEAF{}", parent.0), title);
+        self.last_added()
+    }
+
+    /// Inserts a new branch after `sibling` at the same depth, returning the id of the new node.
+    ///
+    /// Ignored if called before the tree is mapped.
+    pub fn insert_branch<S: Into<String>>(&mut self, sibling: NodeId, title: S) -> Option<NodeId> {
+        self.set_attrib(format!("INSERTBRANCH{}", sibling.0), title);
+        self.last_added()
+    }
+
+    /// Inserts a new leaf after `sibling` at the same depth, returning the id of the new node.
+    ///
+    /// Ignored if called before the tree is mapped.
+    pub fn insert_leaf<S: Into<String>>(&mut self, sibling: NodeId, title: S) -> Option<NodeId> {
+        self.set_attrib(format!("INSERTLEAF{}", sibling.0), title);
+        self.last_added()
+    }
+
+    /// The id of the last node added or inserted.
+    fn last_added(&self) -> Option<NodeId> {
+        self.attrib_parse("LASTADDNODE").map(NodeId)
+    }
+
+    /// Removes a node and all of its children.
+    pub fn remove(&mut self, id: NodeId) -> Self {
+        self.set_attrib(format!("DELNODE{}", id.0), "SELECTED")
+    }
+
+    /// Removes only the children of a node.
+    pub fn remove_children(&mut self, id: NodeId) -> Self {
+        self.set_attrib(format!("DELNODE{}", id.0), "CHILDREN")
+    }
+
+    /// Removes all the marked (selected) nodes.
+    pub fn remove_marked(&mut self) -> Self {
+        self.set_attrib("DELNODE", "MARKED")
+    }
+
+    /// Removes all nodes of the tree.
+    pub fn clear(&mut self) -> Self {
+        self.set_attrib("DELNODE", "ALL")
+    }
+
+    /// Moves a node and its children to the `dest` node.
+    ///
+    /// If `dest` is an expanded branch the node becomes its first child, otherwise it's placed
+    /// after `dest` at the same depth. The node can not be moved into one of its descendants.
+    pub fn move_node(&mut self, id: NodeId, dest: NodeId) -> Self {
+        self.set_attrib(format!("MOVENODE{}", id.0), dest.0.to_string())
+    }
+
+    /// Copies a node and its children to the `dest` node.
+    ///
+    /// Follows the same placement rules of `Tree::move_node`. The user data is not copied.
+    pub fn copy_node(&mut self, id: NodeId, dest: NodeId) -> Self {
+        self.set_attrib(format!("COPYNODE{}", id.0), dest.0.to_string())
+    }
+
+    /// Gets the total number of nodes in the tree.
+    pub fn count(&self) -> usize {
+        self.attrib_parse("COUNT").unwrap_or(0)
+    }
+
+    /// Gets the title of a node.
+    pub fn title(&self, id: NodeId) -> Option<String> {
+        self.attrib(format!("TITLE{}", id.0))
+    }
+
+    /// Sets the title of a node.
+    pub fn set_title<S: Into<String>>(&mut self, id: NodeId, title: S) -> Self {
+        self.set_attrib(format!("TITLE{}", id.0), title)
+    }
+
+    /// Sets the image of a node. For branches this is the image shown when collapsed.
+    pub fn set_image<I: ImageElement>(&mut self, id: NodeId, image: I) -> Self {
+        self.set_attrib_handle(format!("IMAGE{}", id.0), image)
+    }
+
+    /// Sets the image of a branch when it's expanded.
+    pub fn set_image_expanded<I: ImageElement>(&mut self, id: NodeId, image: I) -> Self {
+        self.set_attrib_handle(format!("IMAGEEXPANDED{}", id.0), image)
+    }
+
+    /// Gets the text color of a node.
+    pub fn color(&self, id: NodeId) -> Option<Color> {
+        self.attrib_value(format!("COLOR{}", id.0))
+    }
+
+    /// Sets the text color of a node.
+    pub fn set_color<C: Into<Color>>(&mut self, id: NodeId, color: C) -> Self {
+        self.set_attrib_value(format!("COLOR{}", id.0), &color.into())
+    }
+
+    /// Gets whether a node is a branch or a leaf.
+    pub fn kind(&self, id: NodeId) -> Option<NodeKind> {
+        match self.attrib(format!("KIND{}", id.0)) {
+            Some(ref s) if s == "BRANCH" => Some(NodeKind::Branch),
+            Some(ref s) if s == "LEAF" => Some(NodeKind::Leaf),
+            _ => None,
+        }
+    }
+
+    /// Gets the state of a branch. Returns `None` for leaves.
+    pub fn state(&self, id: NodeId) -> Option<NodeState> {
+        match self.attrib(format!("STATE{}", id.0)) {
+            Some(ref s) if s == "EXPANDED" => Some(NodeState::Expanded),
+            Some(ref s) if s == "COLLAPSED" => Some(NodeState::Collapsed),
+            _ => None,
+        }
+    }
+
+    /// Expands or collapses a branch.
+    pub fn set_state(&mut self, id: NodeId, state: NodeState) -> Self {
+        self.set_attrib(format!("STATE{}", id.0), match state {
+            NodeState::Expanded => "EXPANDED",
+            NodeState::Collapsed => "COLLAPSED",
+        })
+    }
+
+    /// Expands a branch. Shortcut to `set_state(id, NodeState::Expanded)`.
+    pub fn expand_node(&mut self, id: NodeId) -> Self {
+        self.set_state(id, NodeState::Expanded)
+    }
+
+    /// Collapses a branch. Shortcut to `set_state(id, NodeState::Collapsed)`.
+    pub fn collapse_node(&mut self, id: NodeId) -> Self {
+        self.set_state(id, NodeState::Collapsed)
+    }
+
+    /// Expands or collapses all the branches of the tree.
+    pub fn expand_all(&mut self, expand: bool) -> Self {
+        self.set_attrib("EXPANDALL", if expand { "YES" } else { "NO" })
+    }
+
+    /// Gets the parent of a node, `None` for nodes at the first level.
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.attrib_parse(format!("PARENT{}", id.0)).map(NodeId)
+    }
+
+    /// Gets the depth of a node, the nodes at the first level have depth 0.
+    pub fn depth(&self, id: NodeId) -> Option<u32> {
+        self.attrib_parse(format!("DEPTH{}", id.0))
+    }
+
+    /// Gets the number of immediate children of a node.
+    pub fn child_count(&self, id: NodeId) -> usize {
+        self.attrib_parse(format!("CHILDCOUNT{}", id.0)).unwrap_or(0)
+    }
+
+    /// Gets the number of all the descendants of a node.
+    pub fn total_child_count(&self, id: NodeId) -> usize {
+        self.attrib_parse(format!("TOTALCHILDCOUNT{}", id.0)).unwrap_or(0)
+    }
+
+    /// Gets the node with the focus, i.e. the current node.
+    pub fn focused(&self) -> Option<NodeId> {
+        self.attrib_parse("VALUE").map(NodeId)
+    }
+
+    /// Sets the node with the focus. In single selection mode the node is also selected.
+    pub fn set_focused(&mut self, id: NodeId) -> Self {
+        self.set_attrib("VALUE", id.0.to_string())
+    }
+
+    /// Allows multiple nodes to be marked (selected) at once. Default `false`.
+    pub fn set_multiple_selection(&mut self, multiple: bool) -> Self {
+        self.set_attrib("MARKMODE", if multiple { "MULTIPLE" } else { "SINGLE" })
+    }
+
+    /// Whether a node is marked (selected).
+    pub fn is_marked(&self, id: NodeId) -> bool {
+        self.attrib_bool(format!("MARKED{}", id.0)).unwrap_or(false)
+    }
+
+    /// Marks (selects) or unmarks a node.
+    pub fn set_marked(&mut self, id: NodeId, marked: bool) -> Self {
+        self.set_attrib(format!("MARKED{}", id.0), if marked { "YES" } else { "NO" })
+    }
+
+    /// Gets all the marked (selected) nodes.
+    pub fn marked_nodes(&self) -> Vec<NodeId> {
+        self.attrib("MARKEDNODES").map(|marks| {
+            marks.chars().enumerate()
+                 .filter(|&(_, mark)| mark == '+')
+                 .map(|(i, _)| NodeId(i as i32))
+                 .collect()
+        }).unwrap_or_default()
+    }
+
+    /// Gets the user data associated with a node, or null if none.
+    ///
+    /// # Safety
+    /// The tree does not own the data, the returned pointer may be dangling if the data was
+    /// freed while still associated with the node.
+    pub unsafe fn userdata(&self, id: NodeId) -> *mut c_void {
+        iup_sys::IupTreeGetUserId(self.raw(), id.0)
+    }
+
+    /// Associates user data with a node.
+    ///
+    /// # Safety
+    /// The tree does not own the data, the caller must keep it alive while associated with the
+    /// node and free it when the node is removed.
+    pub unsafe fn set_userdata(&mut self, id: NodeId, data: *mut c_void) -> Self {
+        iup_sys::IupTreeSetUserId(self.raw(), id.0, data);
+        *self
+    }
+
+    /// Finds the node associated with the specified user data.
+    ///
+    /// The pointer is only compared with the user data of the nodes, never dereferenced.
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn find_userdata(&self, data: *mut c_void) -> Option<NodeId> {
+        match unsafe { iup_sys::IupTreeGetId(self.raw(), data) } {
+            -1 => None,
+            id => Some(NodeId(id)),
+        }
+    }
+}
+
+impl_widget!(Tree, "tree");
+impl ::callback::MapCb for Tree {}
+impl ::callback::UnmapCb for Tree {}
+impl ::callback::GetFocusCb for Tree {}
+impl ::callback::KillFocusCb for Tree {}
+impl ::callback::EnterWindowCb for Tree {}
+impl ::callback::LeaveWindowCb for Tree {}
+impl ::callback::HelpCb for Tree {}
+impl ::callback::key::KAnyCb for Tree {}
+
+/// Action generated when any mouse button is pressed or released inside the tree.
+impl ::callback::button::ButtonCb for Tree {}
+
+/// Action generated when the mouse is moved over the tree.
+impl ::callback::button::MotionCb for Tree {}
+
+/// Action generated when one or more files are dropped in the tree.
+impl ::callback::DropFilesCb for Tree {}
+
+/// Action generated when the right mouse button is pressed over a node.
+///
+/// The `i32` parameter is the id of the node, i.e. a `NodeId`.
+impl ::callback::RightClickCb for Tree {}
+
+/// Action generated when a node is dropped over another node, when SHOWDRAGDROP is enabled.
+///
+/// The `i32` parameters are the id of the dragged node and the id of the node it was dropped
+/// over, i.e. `NodeId`s.
+///
+/// If the callback returns `CallbackReturn::Continue` or is not defined the node is moved
+/// to the new position, or copied if Ctrl was pressed.
+impl ::callback::DragDropCb for Tree {}
+
+/// See the `SelectionCb` documentation.
+impl self::SelectionCb for Tree {}
+
+/// See the `MultiSelectionCb` documentation.
+impl self::MultiSelectionCb for Tree {}
+
+/// See the `BranchOpenCb` documentation.
+impl self::BranchOpenCb for Tree {}
+
+/// See the `BranchCloseCb` documentation.
+impl self::BranchCloseCb for Tree {}
+
+/// See the `ExecuteLeafCb` documentation.
+impl self::ExecuteLeafCb for Tree {}
+
+/// See the `RenameCb` documentation.
+impl self::RenameCb for Tree {}
+
+impl_callback! {
+    #[doc="Action generated when a node is selected or deselected."]
+    #[doc=""]
+    #[doc="The `bool` parameter is `true` if the node was selected and `false` if deselected."]
+    pub trait SelectionCb where Self: Element {
+        let name = "SELECTION_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, id: c_int, status: c_int) -> CallbackReturn;
+        fn set_selection_cb<F: Callback(Self, NodeId, bool)>(&mut self, cb: F) -> Self;
+        fn remove_selection_cb(&mut self) -> Option<Box<_>>;
+    }
+}
+
+impl_callback! {
+    #[doc="Action generated after a continuous range of nodes is selected in one single operation,"]
+    #[doc="e.g. with Shift and click, in multiple selection mode."]
+    #[doc=""]
+    #[doc="If not defined the `SelectionCb` is called for each node of the range."]
+    pub trait MultiSelectionCb where Self: Element {
+        let name = "MULTISELECTION_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, ids: *const c_int, n: c_int) -> CallbackReturn;
+        fn set_multiselection_cb<F: Callback(Self, Vec<NodeId>)>(&mut self, cb: F) -> Self;
+        fn remove_multiselection_cb(&mut self) -> Option<Box<_>>;
+
+        fn resolve_args(elem: Self, ids: *const c_int, n: c_int) -> (Self, Vec<NodeId>) {
+            let ids = unsafe { slice::from_raw_parts(ids, n as usize) };
+            (elem, ids.iter().map(|&id| NodeId(id)).collect())
+        }
+    }
+}
+
+impl_callback! {
+    #[doc="Action generated when a branch is expanded."]
+    #[doc=""]
+    #[doc="If `CallbackReturn::Ignore` is returned the branch is not expanded."]
+    pub trait BranchOpenCb where Self: Element {
+        let name = "BRANCHOPEN_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, id: c_int) -> CallbackReturn;
+        fn set_branchopen_cb<F: Callback(Self, NodeId)>(&mut self, cb: F) -> Self;
+        fn remove_branchopen_cb(&mut self) -> Option<Box<_>>;
+    }
+}
+
+impl_callback! {
+    #[doc="Action generated when a branch is collapsed."]
+    #[doc=""]
+    #[doc="If `CallbackReturn::Ignore` is returned the branch is not collapsed."]
+    pub trait BranchCloseCb where Self: Element {
+        let name = "BRANCHCLOSE_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, id: c_int) -> CallbackReturn;
+        fn set_branchclose_cb<F: Callback(Self, NodeId)>(&mut self, cb: F) -> Self;
+        fn remove_branchclose_cb(&mut self) -> Option<Box<_>>;
+    }
+}
+
+impl_callback! {
+    #[doc="Action generated when a leaf is executed, i.e. double clicked or Enter is pressed."]
+    pub trait ExecuteLeafCb where Self: Element {
+        let name = "EXECUTELEAF_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, id: c_int) -> CallbackReturn;
+        fn set_executeleaf_cb<F: Callback(Self, NodeId)>(&mut self, cb: F) -> Self;
+        fn remove_executeleaf_cb(&mut self) -> Option<Box<_>>;
+    }
+}
+
+impl_callback! {
+    #[doc="Action generated after a node was renamed in place by the user, when SHOWRENAME is enabled."]
+    #[doc=""]
+    #[doc="The `String` parameter is the new title of the node."]
+    #[doc=""]
+    #[doc="If `CallbackReturn::Ignore` is returned the node keeps its old title."]
+    pub trait RenameCb where Self: Element {
+        let name = "RENAME_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, id: c_int, title: *const c_char) -> CallbackReturn;
+        fn set_rename_cb<F: Callback(Self, NodeId, String)>(&mut self, cb: F) -> Self;
+        fn remove_rename_cb(&mut self) -> Option<Box<_>>;
+    }
+}
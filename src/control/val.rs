@@ -0,0 +1,112 @@
+use iup_sys;
+
+use Element;
+use Orientation;
+
+/// A valuator control, i.e. a slider to select a value inside a range.
+///
+/// The value is changed by dragging the handler, by the keyboard arrows (by `step`) and by the
+/// Page Up and Page Down keys (by `pagestep`).
+///
+/// See the [IUP Val Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupval.html
+pub struct Val(*mut iup_sys::Ihandle);
+
+impl Val {
+    /// Creates a valuator in the specified orientation.
+    pub fn new(orient: Orientation) -> Val {
+        unsafe { Val::from_raw(iup_sys::IupVal(orient.as_cstr())) }
+    }
+
+    /// Gets the orientation of the valuator.
+    pub fn orientation(&self) -> Orientation {
+        match self.attrib("ORIENTATION") {
+            Some(ref s) if s == "VERTICAL" => Orientation::Vertical,
+            _ => Orientation::Horizontal,
+        }
+    }
+
+    impl_attribs! {
+        /// The current value of the valuator, it's clamped to the `min` and `max` range.
+        pub fn value, set_value: f64 = "VALUE", default 0.0;
+
+        /// The minimum value. Default `0.0`.
+        pub fn min, set_min: f64 = "MIN", default 0.0;
+
+        /// The maximum value. Default `1.0`.
+        pub fn max, set_max: f64 = "MAX", default 1.0;
+
+        /// The increment used by the keyboard arrows, as a fraction of the range. Default `0.01`.
+        pub fn step, set_step: f64 = "STEP", default 0.01;
+
+        /// The increment used by Page Up and Page Down, as a fraction of the range. Default `0.1`.
+        pub fn pagestep, set_pagestep: f64 = "PAGESTEP", default 0.1;
+
+        /// The number of tick marks shown along the valuator. Default `0`, no ticks.
+        ///
+        /// In Windows the value is used only to calculate the ticks spacing and the minimum is 2.
+        pub fn ticks, set_ticks: u32 = "SHOWTICKS", default 0;
+    }
+
+    /// Sets both the minimum and maximum values.
+    pub fn set_range(&mut self, min: f64, max: f64) -> Self {
+        self.set_min(min);
+        self.set_max(max)
+    }
+
+    /// Gets the side of the valuator where the ticks are shown.
+    pub fn ticks_pos(&self) -> TicksPos {
+        match self.attrib("TICKSPOS") {
+            Some(ref s) if s == "REVERSE" => TicksPos::Reverse,
+            Some(ref s) if s == "BOTH" => TicksPos::Both,
+            _ => TicksPos::Normal,
+        }
+    }
+
+    /// Sets the side of the valuator where the ticks are shown. Default `TicksPos::Normal`.
+    ///
+    /// \[Windows Only\], in other systems the ticks are always at the normal position.
+    pub fn set_ticks_pos(&mut self, pos: TicksPos) -> Self {
+        self.set_attrib("TICKSPOS", match pos {
+            TicksPos::Normal => "NORMAL",
+            TicksPos::Reverse => "REVERSE",
+            TicksPos::Both => "BOTH",
+        })
+    }
+
+    /// Inverts the minimum and maximum positions on screen. Default `false`, which places the
+    /// maximum at the right for horizontal valuators and at the top for vertical ones.
+    pub fn set_inverted(&mut self, inverted: bool) -> Self {
+        self.set_attrib("INVERTED", if inverted { "YES" } else { "NO" })
+    }
+}
+
+impl_widget!(Val, "val");
+impl ::callback::MapCb for Val {}
+impl ::callback::UnmapCb for Val {}
+impl ::callback::GetFocusCb for Val {}
+impl ::callback::KillFocusCb for Val {}
+impl ::callback::EnterWindowCb for Val {}
+impl ::callback::LeaveWindowCb for Val {}
+impl ::callback::HelpCb for Val {}
+impl ::callback::key::KAnyCb for Val {}
+
+/// Called after the value was interactively changed by the user.
+///
+/// While the handler is dragged this is called for every intermediate value, the drag can be
+/// detected with `ValueChangingCb` to postpone expensive updates until the drag ends.
+impl ::callback::ValueChangedCb for Val {}
+
+/// Called when the user starts (`true`) and ends (`false`) dragging the handler.
+impl ::callback::ValueChangingCb for Val {}
+
+/// The side of a `Val` where the ticks are shown.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TicksPos {
+    /// Below horizontal valuators and to the right of vertical ones.
+    Normal,
+    /// Above horizontal valuators and to the left of vertical ones.
+    Reverse,
+    /// On both sides.
+    Both,
+}
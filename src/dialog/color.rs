@@ -0,0 +1,128 @@
+use iup_sys;
+
+use Element;
+use dialog::{DialogElement, DialogPos};
+use attrib::Color;
+
+/// Shows a modal dialog to select a color. It's a simpler version of `ColorDlg`.
+///
+/// Returns the selected color or `None` if the dialog was cancelled.
+///
+/// # Panics
+/// Panics if `x` is either `Bottom` or `Top` or if `y` is either `Left` or `Right`.
+pub fn get_color(x: DialogPos, y: DialogPos) -> Option<Color> {
+    let (mut r, mut g, mut b) = (0, 0, 0);
+    match unsafe { iup_sys::IupGetColor(x.to_raw_x(), y.to_raw_y(), &mut r, &mut g, &mut b) } {
+        1 => Some(Color::rgb(r, g, b)),
+        _ => None,
+    }
+}
+
+/// A predefined dialog for selecting a color.
+///
+/// The dialog can be shown with the `popup` method only.
+///
+/// See the [IUP ColorDlg Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/dlg/iupcolordlg.html
+pub struct ColorDlg(*mut iup_sys::Ihandle);
+
+impl ColorDlg {
+    /// Creates a color dialog.
+    pub fn new() -> ColorDlg {
+        unsafe { ColorDlg::from_raw(iup_sys::IupColorDlg()) }
+    }
+
+    impl_attribs! {
+        /// The text of the dialog title bar.
+        pub fn title, set_title: String = "TITLE";
+
+        /// Shows the hexadecimal representation of the color. Default `false`.
+        pub fn show_hex, set_show_hex: bool = "SHOWHEX", default false;
+
+        /// Shows the color table. Default `false`, or `true` when `ColorDlg::set_color_table`
+        /// is used.
+        pub fn show_color_table, set_show_color_table: bool = "SHOWCOLORTABLE", default false;
+    }
+
+    /// Gets the initial color of the dialog, and after it's closed the selected color.
+    ///
+    /// The alpha component is set only when alpha is enabled with `ColorDlg::set_alpha`.
+    pub fn value(&self) -> Option<Color> {
+        self.attrib_value::<Color, _>("VALUE").map(|color| {
+            match self.alpha() {
+                Some(alpha) => Color { a: alpha, ..color },
+                None => color,
+            }
+        })
+    }
+
+    /// Sets the initial color of the dialog.
+    ///
+    /// The alpha component is used only when alpha is enabled with `ColorDlg::set_alpha`.
+    pub fn set_value<C: Into<Color>>(&mut self, color: C) -> Self {
+        let color = color.into();
+        if self.alpha().is_some() {
+            self.set_alpha(color.a);
+        }
+        self.set_attrib_value("VALUE", &color.opaque())
+    }
+
+    /// Gets the alpha of the color, `None` if alpha is not enabled.
+    pub fn alpha(&self) -> Option<u8> {
+        self.attrib_parse("ALPHA")
+    }
+
+    /// Enables the selection of the alpha component with the specified initial value.
+    pub fn set_alpha(&mut self, alpha: u8) -> Self {
+        self.set_attrib("ALPHA", alpha.to_string())
+    }
+
+    /// Sets the colors of the color table, up to 20 colors.
+    ///
+    /// Also shows the color table. The alpha of the colors is ignored.
+    pub fn set_color_table<U, C>(&mut self, colors: U) -> Self
+                                        where U: AsRef<[C]>, C: Into<Color> + Copy {
+        let table = colors.as_ref().iter()
+                          .map(|&color| color.into().opaque().to_string())
+                          .collect::<Vec<_>>();
+        self.set_attrib("COLORTABLE", table.join(";"))
+    }
+
+    /// Gets the colors of the color table.
+    pub fn color_table(&self) -> Vec<Color> {
+        self.attrib("COLORTABLE").map(|table| {
+            table.split(';').filter_map(|color| color.parse().ok()).collect()
+        }).unwrap_or_default()
+    }
+
+    /// Shows the dialog and returns the selected color, or `None` if the dialog was cancelled.
+    ///
+    /// # Panics
+    /// Panics if `x` is either `Bottom` or `Top` or if `y` is either `Left` or `Right`.
+    pub fn popup(&mut self, x: DialogPos, y: DialogPos) -> Option<Color> {
+        DialogElement::popup(self, x, y).ok().and_then(|dlg| {
+            match dlg.attrib("STATUS") {
+                Some(ref status) if status == "1" => dlg.value(),
+                _ => None,
+            }
+        })
+    }
+}
+
+impl_dialog!(ColorDlg, "colordlg");
+impl ::callback::HelpCb for ColorDlg {}
+
+/// See the `ColorUpdateCb` documentation.
+impl self::ColorUpdateCb for ColorDlg {}
+
+impl_callback! {
+    #[doc="Called when the user changes the color while the dialog is shown."]
+    #[doc=""]
+    #[doc="The current color can be read with `ColorDlg::value`, e.g. for a live preview."]
+    pub trait ColorUpdateCb where Self: Element {
+        let name = "COLORUPDATE_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle) -> CallbackReturn;
+        fn set_colorupdate_cb<F: Callback(Self)>(&mut self, cb: F) -> Self;
+        fn remove_colorupdate_cb(&mut self) -> Option<Box<_>>;
+    }
+}
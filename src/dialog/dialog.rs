@@ -1,10 +1,15 @@
 use iup_sys;
 use libc::{c_char, c_int};
+use std::any::Any;
+use std::collections::HashMap;
+use std::mem::transmute;
 use std::ptr;
 
 use Element;
-use callback::IntoRust;
+use callback::{Callback, IntoRust};
 use callback::button::{MouseButton, MouseButtonState};
+use callback::key::Key;
+use menu::Menu;
 
 
 /// Dialog elements manages user interaction with the interface elements.
@@ -24,6 +29,111 @@ impl Dialog {
     pub fn new_empty() -> Dialog {
         unsafe { Dialog::from_raw(iup_sys::IupDialog(ptr::null_mut())) }
     }
+
+    impl_attribs! {
+        /// The text of the dialog title bar.
+        pub fn title, set_title: String = "TITLE";
+
+        /// Whether the dialog can be resized by the user. Default `true`.
+        pub fn resize, set_resize: bool = "RESIZE", default true;
+
+        /// Whether the title bar has a maximize button. Default `true`.
+        pub fn maxbox, set_maxbox: bool = "MAXBOX", default true;
+
+        /// Whether the title bar has a minimize button. Default `true`.
+        pub fn minbox, set_minbox: bool = "MINBOX", default true;
+
+        /// Whether the title bar has a system menu, with the close button. Default `true`.
+        pub fn menubox, set_menubox: bool = "MENUBOX", default true;
+
+        /// Whether the dialog has a resize border. Default `true`.
+        pub fn border, set_border: bool = "BORDER", default true;
+
+        /// Whether the dialog occupies the whole screen, with no decorations. Default `false`.
+        pub fn fullscreen, set_fullscreen: bool = "FULLSCREEN", default false;
+
+        /// Whether the dialog stays on top of all the other windows. Default `false`.
+        pub fn topmost, set_topmost: bool = "TOPMOST", default false;
+    }
+
+    /// Sets the menu bar of the dialog.
+    ///
+    /// The menu is destroyed together with the dialog. To replace the menu bar of a dialog,
+    /// the previous menu must be destroyed manually after setting the new one.
+    pub fn set_menu(&mut self, menu: Menu) -> Self {
+        self.set_attrib_handle("MENU", menu)
+    }
+
+    /// Gets the menu bar of the dialog, if any.
+    pub fn menu(&self) -> Option<Menu> {
+        // `attrib_handle` takes `&mut self` though it doesn't change the element.
+        let mut dialog = *self;
+        dialog.attrib_handle("MENU").and_then(|handle| Menu::from_handle(handle).ok())
+    }
+
+    /// Associates a closure to be called when `key` is pressed anywhere inside the dialog.
+    ///
+    /// Keys go first to the `KAnyCb` of the element with the focus and are propagated to the
+    /// dialog only if not handled there. Registering the same key twice replaces the previous
+    /// closure.
+    ///
+    /// Shortcuts are dispatched from the dialog `K_ANY` callback. A closure set with
+    /// `KAnyCb::set_k_any` on the dialog receives every key with no shortcut, setting or
+    /// removing it doesn't affect the shortcuts.
+    ///
+    /// # Example
+    /// ```ignore
+    /// dialog.set_shortcut(Key::ctrl('s'), |(_, _)| save_document());
+    /// ```
+    pub fn set_shortcut<F>(&mut self, key: Key, cb: F) -> Self
+                                    where F: Callback<(Dialog, Key)> {
+        unsafe {
+            shortcuts(self.raw(), true).unwrap().insert(key, Box::new(cb));
+            let listener = transmute::<*const (), iup_sys::Icallback>(on_shortcut as *const ());
+            iup_sys::IupSetCallback(self.raw(), cstr!("K_ANY"), listener);
+        }
+        *self
+    }
+
+    /// Removes a previously set shortcut, returning its closure.
+    pub fn remove_shortcut(&mut self, key: Key) -> Option<Box<dyn Callback<(Dialog, Key)>>> {
+        unsafe { shortcuts(self.raw(), false).and_then(|map| map.remove(&key)) }
+    }
+}
+
+type Shortcuts = HashMap<Key, Box<dyn Callback<(Dialog, Key)>>>;
+
+/// Gets the shortcut table of the dialog `ih`, creating it if `create` is true.
+///
+/// The table is boxed as `Any` in a callback attribute so that `drop_callbacks` frees it.
+unsafe fn shortcuts<'a>(ih: *mut iup_sys::Ihandle, create: bool) -> Option<&'a mut Shortcuts> {
+    let mut capsule = iup_sys::IupGetAttribute(ih, fbox_c_str!("SHORTCUTS")) as *mut Box<dyn Any>;
+    if capsule.is_null() {
+        if !create {
+            return None;
+        }
+        let table: Box<Box<dyn Any>> = Box::new(Box::new(Shortcuts::new()));
+        capsule = Box::into_raw(table);
+        iup_sys::IupSetAttribute(ih, fbox_c_str!("SHORTCUTS"), capsule as *const _);
+    }
+    (*capsule).downcast_mut::<Shortcuts>()
+}
+
+extern "C" fn on_shortcut(ih: *mut iup_sys::Ihandle, c: c_int) -> c_int {
+    let key: Key = c.into_rust();
+    let dialog = unsafe { Dialog::from_raw_unchecked(ih) };
+    if let Some(cb) = unsafe { shortcuts(ih, false) }.and_then(|map| map.get_mut(&key)) {
+        return cb.on_callback((dialog, key));
+    }
+    // Fall back to the closure of `KAnyCb`, if any.
+    let fbox = unsafe {
+        iup_sys::IupGetAttribute(ih, fbox_c_str!("K_ANY")) as *mut Box<dyn Callback<(Dialog, Key)>>
+    };
+    if fbox.is_null() {
+        iup_sys::IUP_CONTINUE
+    } else {
+        unsafe { (*fbox).on_callback((dialog, key)) }
+    }
 }
 
 impl_dialog!(Dialog, "dialog");
@@ -34,7 +144,32 @@ impl ::callback::KillFocusCb for Dialog {}
 impl ::callback::EnterWindowCb for Dialog {}
 impl ::callback::LeaveWindowCb for Dialog {}
 impl ::callback::HelpCb for Dialog {}
-// TODO impl K_ callbacks when it's implemented.
+
+/// Called for keys pressed in the dialog that weren't handled by the element with the focus.
+///
+/// See also `Dialog::set_shortcut`.
+/// Keys with a shortcut are handled by the `Dialog::set_shortcut` closures, the other keys are
+/// passed to the `set_k_any` closure.
+impl ::callback::key::KAnyCb for Dialog {
+    fn set_k_any<F>(&mut self, cb: F) -> Dialog where F: Callback<(Dialog, Key)> {
+        unsafe {
+            set_fbox_callback!(self.raw(), "K_ANY", on_shortcut, cb, Callback<(Dialog, Key)>);
+        }
+        *self
+    }
+
+    fn remove_k_any(&mut self) -> Option<Box<dyn Callback<(Dialog, Key)>>> {
+        unsafe {
+            let old_cb = clear_fbox_callback!(self.raw(), "K_ANY", Callback<(Dialog, Key)>);
+            // Clearing the closure also cleared the listener, which still dispatches shortcuts.
+            if shortcuts(self.raw(), false).is_some() {
+                let listener = transmute::<*const (), iup_sys::Icallback>(on_shortcut as *const ());
+                iup_sys::IupSetCallback(self.raw(), cstr!("K_ANY"), listener);
+            }
+            old_cb
+        }
+    }
+}
 
 /// Called right before the dialog is closed.
 impl ::callback::CloseCb for Dialog {}
@@ -113,8 +248,11 @@ impl_callback! {
     pub trait ShowCb where Self: Element {
         let name = "SHOW_CB";
         extern fn listener(ih: *mut iup_sys::Ihandle, state: c_int) -> CallbackReturn;
-        fn set_move_cb<F: Callback(Self, ShowState)>(&mut self, cb: F) -> Self;
-        fn remove_move_cb(&mut self) -> Option<Box<_>>;
+        fn set_show_cb<F: Callback(Self, ShowState)>(&mut self, cb: F) -> Self;
+        fn remove_show_cb(&mut self) -> Option<Box<_>>;
+
+        #[deprecated(note = "renamed to `set_show_cb` and `remove_show_cb`")]
+        fn set_move_cb, remove_move_cb;
     }
 }
 
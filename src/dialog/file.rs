@@ -1,8 +1,38 @@
 use iup_sys;
+use libc::c_char;
 use std::path::{Path, PathBuf};
 
 use Element;
 
+/// Shows a modal dialog to select a file. It simply creates and popup a `FileDlg`.
+///
+/// The `filter` is a wildcard pattern for the files shown, e.g. `"*.txt"`, and may be
+/// preceded by a directory, e.g. `"/home/user/*.txt"`.
+///
+/// Returns the selected file, which may not exist, or `None` if the dialog was cancelled.
+pub fn get_file<S: Into<String>>(filter: S) -> Option<PathBuf> {
+    let mut buf = filter.into().into_bytes();
+    buf.truncate(GET_FILE_SIZE - 1);
+    buf.resize(GET_FILE_SIZE, 0);
+    match unsafe { iup_sys::IupGetFile(buf.as_mut_ptr() as *mut c_char) } {
+        -1 => None,
+        _ => {
+            let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+            Some(PathBuf::from(String::from_utf8_lossy(&buf[..len]).into_owned()))
+        },
+    }
+}
+
+/// The size of the buffer of `get_file`, including the nul terminator.
+const GET_FILE_SIZE: usize = 4096;
+
+/// A predefined dialog for selecting files or a directory.
+///
+/// The dialog can be shown with the `popup` method only. After it's closed the selection is
+/// available with `FileDlg::files` or `FileDlg::path` and `FileDlg::status`.
+///
+/// See the [IUP FileDlg Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/dlg/iupfiledlg.html
 pub struct FileDlg(*mut iup_sys::Ihandle);
 
 impl FileDlg {
@@ -42,9 +72,147 @@ impl FileDlg {
 
     pub fn path(&self) -> Option<PathBuf> {
         self.attrib("VALUE").map(|value| value.into())
-    } 
+    }
+
+    /// Gets how the dialog was closed.
+    pub fn status(&self) -> FileStatus {
+        match self.attrib("STATUS") {
+            Some(ref s) if s == "1" => FileStatus::New,
+            Some(ref s) if s == "0" => FileStatus::Existing,
+            _ => FileStatus::Cancelled,
+        }
+    }
+
+    impl_attribs! {
+        /// The text of the dialog title bar.
+        pub fn title, set_title: String = "TITLE";
+
+        /// Allows the selection of multiple files in an open dialog. Default `false`.
+        pub fn multiple_files, set_multiple_files: bool = "MULTIPLEFILES", default false;
 
+        /// Disables the confirmation prompt when an existing file is selected in a save dialog.
+        /// Default `false`.
+        pub fn no_overwrite_prompt, set_no_overwrite_prompt: bool = "NOOVERWRITEPROMPT",
+                                                               default false;
+
+        /// Allows the selection of a file that does not exist. Default `false` for open
+        /// dialogs and `true` for save dialogs.
+        pub fn allow_new, set_allow_new: bool = "ALLOWNEW";
+    }
+
+    /// Sets the initial directory of the dialog.
+    pub fn set_directory<P: AsRef<Path>>(&mut self, dir: P) -> Self {
+        self.set_attrib("DIRECTORY", dir.as_ref().to_string_lossy().into_owned())
+    }
+
+    /// Sets the initial file name of the dialog, optionally with a directory.
+    pub fn set_file<P: AsRef<Path>>(&mut self, file: P) -> Self {
+        self.set_attrib("FILE", file.as_ref().to_string_lossy().into_owned())
+    }
+
+    /// Sets the list of filters the user can select from, the first is selected initially.
+    pub fn set_filters<U>(&mut self, filters: U) -> Self where U: AsRef<[FileFilter]> {
+        let value = filters.as_ref().iter()
+                           .map(|filter| format!("{}|{}|", filter.description,
+                                                           filter.patterns.join(";")))
+                           .collect::<String>();
+        self.set_attrib("EXTFILTER", value)
+    }
 }
 
 impl_dialog!(FileDlg, "filedlg");
 impl ::callback::HelpCb for FileDlg {}
+
+/// See the `FileCb` documentation.
+impl self::FileCb for FileDlg {}
+
+/// A file filter of a `FileDlg`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FileFilter {
+    /// The text shown to the user, e.g. `"Text Files (*.txt)"`.
+    pub description: String,
+    /// The wildcard patterns of the filter, e.g. `"*.txt"`.
+    pub patterns: Vec<String>,
+}
+
+impl FileFilter {
+    /// Creates a filter with the specified description and patterns.
+    pub fn new<S, P>(description: S, patterns: &[P]) -> FileFilter
+                                        where S: Into<String>, P: AsRef<str> {
+        FileFilter {
+            description: description.into(),
+            patterns: patterns.iter().map(|p| p.as_ref().to_owned()).collect(),
+        }
+    }
+}
+
+/// How a `FileDlg` was closed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FileStatus {
+    /// A new file was selected.
+    New,
+    /// An existing file or a directory was selected.
+    Existing,
+    /// The dialog was cancelled.
+    Cancelled,
+}
+
+/// The reason a `FileCb` was called.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FileCbStatus {
+    /// The dialog was created, before it's shown.
+    Init,
+    /// The dialog is about to be destroyed.
+    Finish,
+    /// A file was selected.
+    Select,
+    /// A file was selected and the user pressed OK. Returning `CallbackReturn::Ignore`
+    /// keeps the dialog open, e.g. when the file is not valid.
+    Ok,
+    /// The preview area must be repainted.
+    Paint,
+    /// The filter was changed.
+    Filter,
+    /// Something else happened, e.g. a directory was selected.
+    Other,
+}
+
+impl FileCbStatus {
+    fn from_str(s: &str) -> FileCbStatus {
+        match s {
+            "INIT" => FileCbStatus::Init,
+            "FINISH" => FileCbStatus::Finish,
+            "SELECT" => FileCbStatus::Select,
+            "OK" => FileCbStatus::Ok,
+            "PAINT" => FileCbStatus::Paint,
+            "FILTER" => FileCbStatus::Filter,
+            _ => FileCbStatus::Other,
+        }
+    }
+}
+
+impl_callback! {
+    #[doc="Called when a file is selected in the dialog, or on other events of the dialog."]
+    #[doc=""]
+    #[doc="The `Option<PathBuf>` parameter is the selected file, if any, and `FileCbStatus`"]
+    #[doc="is the reason of the call. Can be used to preview or validate the selection."]
+    #[doc=""]
+    #[doc="\\[Windows and GTK Only\\]"]
+    pub trait FileCb where Self: Element {
+        let name = "FILE_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, file_name: *const c_char,
+                           status: *const c_char) -> CallbackReturn;
+        fn set_file_cb<F: Callback(Self, Option<PathBuf>, FileCbStatus)>(&mut self, cb: F) -> Self;
+        fn remove_file_cb(&mut self) -> Option<Box<_>>;
+
+        fn resolve_args(elem: Self, file_name: *const c_char, status: *const c_char)
+                                            -> (Self, Option<PathBuf>, FileCbStatus) {
+            let file = if file_name.is_null() {
+                None
+            } else {
+                Some(PathBuf::from(string_from_cstr!(file_name)))
+            };
+            (elem, file, FileCbStatus::from_str(&string_from_cstr!(status)))
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use iup_sys;
+
+use Element;
+use dialog::{DialogElement, DialogPos};
+use attrib::{Font, Color};
+
+/// A predefined dialog for selecting a font.
+///
+/// The dialog can be shown with the `popup` method only.
+///
+/// See the [IUP FontDlg Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/dlg/iupfontdlg.html
+pub struct FontDlg(*mut iup_sys::Ihandle);
+
+impl FontDlg {
+    /// Creates a font dialog.
+    pub fn new() -> FontDlg {
+        unsafe { FontDlg::from_raw(iup_sys::IupFontDlg()) }
+    }
+
+    impl_attribs! {
+        /// The text of the dialog title bar.
+        pub fn title, set_title: String = "TITLE";
+
+        /// The initial font of the dialog, and after it's closed the selected font.
+        pub fn value, set_value: Font = "VALUE";
+
+        /// The initial text color of the dialog, and after it's closed the selected color.
+        ///
+        /// \[Windows Only\]
+        pub fn color, set_color: Color = "COLOR";
+
+        /// The text shown in the preview area.
+        pub fn preview_text, set_preview_text: String = "PREVIEWTEXT";
+    }
+
+    /// Shows the dialog and returns the selected font, or `None` if the dialog was cancelled.
+    ///
+    /// # Panics
+    /// Panics if `x` is either `Bottom` or `Top` or if `y` is either `Left` or `Right`.
+    pub fn popup(&mut self, x: DialogPos, y: DialogPos) -> Option<Font> {
+        DialogElement::popup(self, x, y).ok().and_then(|dlg| {
+            match dlg.attrib("STATUS") {
+                Some(ref status) if status == "1" => dlg.value(),
+                _ => None,
+            }
+        })
+    }
+}
+
+impl_dialog!(FontDlg, "fontdlg");
+impl ::callback::HelpCb for FontDlg {}
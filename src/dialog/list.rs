@@ -0,0 +1,45 @@
+use iup_sys;
+use libc::c_int;
+use std::cmp;
+use std::ffi::CString;
+
+/// Shows a modal dialog to select items from a list.
+///
+/// When `multiple` is `false` only one item can be selected. Returns the positions of the
+/// selected items, starting at 0, or `None` if the dialog was cancelled.
+///
+/// If `items` is empty the dialog is not shown and `None` is returned.
+///
+/// See the [IUP ListDialog Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/dlg/iuplistdialog.html
+pub fn list_dialog<S, U, I>(title: S, items: U, multiple: bool) -> Option<Vec<usize>>
+                where S: Into<String>, U: AsRef<[I]>, I: AsRef<str> {
+    let items = items.as_ref();
+    if items.is_empty() {
+        return None;
+    }
+    let ctitle = CString::new(title.into()).unwrap();
+    let citems = items.iter().map(|item| CString::new(item.as_ref()).unwrap())
+                      .collect::<Vec<_>>();
+    let mut ptrs = citems.iter().map(|item| item.as_ptr()).collect::<Vec<_>>();
+    let mut marks = vec![0 as c_int; items.len()];
+
+    let size = items.len() as c_int;
+    let max_col = items.iter().map(|item| item.as_ref().chars().count()).max().unwrap_or(0);
+    let max_col = cmp::max(max_col, 10) as c_int;
+    let max_lin = items.len().clamp(1, 15) as c_int;
+
+    let selected = unsafe {
+        iup_sys::IupListDialog(if multiple { 2 } else { 1 }, ctitle.as_ptr(), size,
+                               ptrs.as_mut_ptr(), 1, max_col, max_lin, marks.as_mut_ptr())
+    };
+
+    match selected {
+        -1 => None,
+        _ if multiple => Some(marks.iter().enumerate()
+                                   .filter(|&(_, &mark)| mark != 0)
+                                   .map(|(i, _)| i)
+                                   .collect()),
+        pos => Some(vec![pos as usize]),
+    }
+}
@@ -2,6 +2,7 @@ use iup_sys;
 use std::ffi::CString;
 
 use Element;
+use dialog::{DialogElement, DialogPos};
 
 /// Shows a modal dialog containing a message. It simply creates and popup a `MessageDlg`.
 pub fn message<S1, S2>(title: S1, message: S2)
@@ -23,7 +24,127 @@ impl MessageDlg {
     pub fn new() -> MessageDlg {
         unsafe { MessageDlg::from_raw(iup_sys::IupMessageDlg()) }
     }
+
+    impl_attribs! {
+        /// The text of the dialog title bar.
+        pub fn title, set_title: String = "TITLE";
+
+        /// The message shown in the dialog.
+        pub fn value, set_value: String = "VALUE";
+    }
+
+    /// Sets the kind of message, which defines the icon shown. Default `MessageType::Message`.
+    pub fn set_dialog_type(&mut self, kind: MessageType) -> Self {
+        self.set_attrib("DIALOGTYPE", kind.as_str())
+    }
+
+    /// Gets the buttons of the dialog.
+    pub fn buttons(&self) -> MessageButtons {
+        match self.attrib("BUTTONS") {
+            Some(ref s) if s == "OKCANCEL" => MessageButtons::OkCancel,
+            Some(ref s) if s == "RETRYCANCEL" => MessageButtons::RetryCancel,
+            Some(ref s) if s == "YESNO" => MessageButtons::YesNo,
+            Some(ref s) if s == "YESNOCANCEL" => MessageButtons::YesNoCancel,
+            _ => MessageButtons::Ok,
+        }
+    }
+
+    /// Sets the buttons of the dialog. Default `MessageButtons::Ok`.
+    pub fn set_buttons(&mut self, buttons: MessageButtons) -> Self {
+        self.set_attrib("BUTTONS", buttons.as_str())
+    }
+
+    /// Sets the button selected by default, by its position starting at 1. Default 1.
+    pub fn set_button_default(&mut self, pos: u32) -> Self {
+        self.set_attrib("BUTTONDEFAULT", pos.to_string())
+    }
+
+    /// Gets the button pressed by the user after the dialog is closed.
+    ///
+    /// A dialog closed without pressing a button responds as its last button.
+    pub fn response(&self) -> MessageResponse {
+        let pos: usize = self.attrib_parse("BUTTONRESPONSE").unwrap_or(0);
+        let responses = self.buttons().responses();
+        if pos >= 1 && pos <= responses.len() {
+            responses[pos - 1]
+        } else {
+            responses[responses.len() - 1]
+        }
+    }
+
+    /// Shows the dialog centered on its parent and returns the button pressed by the user.
+    pub fn run(&mut self) -> MessageResponse {
+        match self.popup(DialogPos::CenterParent, DialogPos::CenterParent) {
+            Ok(dlg) | Err(dlg) => dlg.response(),
+        }
+    }
 }
 
 impl_dialog!(MessageDlg, "messagedlg");
 impl ::callback::HelpCb for MessageDlg {}
+
+/// The kind of message of a `MessageDlg`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MessageType {
+    Message,
+    Error,
+    Warning,
+    Information,
+    Question,
+}
+
+impl MessageType {
+    fn as_str(self) -> &'static str {
+        match self {
+            MessageType::Message => "MESSAGE",
+            MessageType::Error => "ERROR",
+            MessageType::Warning => "WARNING",
+            MessageType::Information => "INFORMATION",
+            MessageType::Question => "QUESTION",
+        }
+    }
+}
+
+/// The set of buttons of a `MessageDlg`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MessageButtons {
+    Ok,
+    OkCancel,
+    RetryCancel,
+    YesNo,
+    YesNoCancel,
+}
+
+impl MessageButtons {
+    fn as_str(self) -> &'static str {
+        match self {
+            MessageButtons::Ok => "OK",
+            MessageButtons::OkCancel => "OKCANCEL",
+            MessageButtons::RetryCancel => "RETRYCANCEL",
+            MessageButtons::YesNo => "YESNO",
+            MessageButtons::YesNoCancel => "YESNOCANCEL",
+        }
+    }
+
+    /// The response of each button, in order.
+    fn responses(self) -> &'static [MessageResponse] {
+        use self::MessageResponse::*;
+        match self {
+            MessageButtons::Ok => &[Ok],
+            MessageButtons::OkCancel => &[Ok, Cancel],
+            MessageButtons::RetryCancel => &[Retry, Cancel],
+            MessageButtons::YesNo => &[Yes, No],
+            MessageButtons::YesNoCancel => &[Yes, No, Cancel],
+        }
+    }
+}
+
+/// The button pressed in a `MessageDlg`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MessageResponse {
+    Ok,
+    Cancel,
+    Retry,
+    Yes,
+    No,
+}
@@ -2,8 +2,13 @@
 //! [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/dialogs.html
 
 use iup_sys;
-use libc::c_int;
+use libc::{c_int, c_void};
+use std::future::Future;
+use std::mem;
+use std::pin::Pin;
+use std::ptr;
 use std::result::Result;
+use std::task::{Context, Poll, Waker};
 
 use element::{Element, Widget, Container};
 
@@ -18,11 +23,23 @@ pub mod dialog;
 pub mod alarm;
 pub mod message;
 pub mod file;
+pub mod font;
+pub mod color;
+pub mod param;
+pub mod text;
+pub mod list;
+pub mod progress;
 
 pub use self::dialog::{Dialog, ShowState, CopyDataCb, MdiActivateCb, ShowCb, TrayClickCb};
 pub use self::alarm::{AlarmButton, alarm};
-pub use self::message::{MessageDlg, message};
-pub use self::file::{FileDlg};
+pub use self::message::{MessageDlg, MessageType, MessageButtons, MessageResponse, message};
+pub use self::file::{FileDlg, get_file, FileFilter, FileStatus, FileCbStatus, FileCb};
+pub use self::font::FontDlg;
+pub use self::color::{ColorDlg, ColorUpdateCb, get_color};
+pub use self::param::{ParamDialog, ParamValue, ParamValues, ParamId, ParamType};
+pub use self::text::get_text;
+pub use self::list::list_dialog;
+pub use self::progress::{ProgressDlg, ProgressState, CancelCb};
 
 // An dialog is a top-level container.
 pub trait DialogElement : Element + Widget + Container {
@@ -57,7 +74,7 @@ pub trait DialogElement : Element + Widget + Container {
 	/// the reverse order they were created or unpredictable results will occur.
 	///
 	/// For a dialog this function will only return the control to the application after a callback
-	/// returns `CallbackReturn::Close`, IupExitLoop (TODO) is called, or when the popup dialog is 
+	/// returns `CallbackReturn::Close`, `exit_loop` is called, or when the popup dialog is
 	/// hidden, for example using `Widget::hide`. For a menu it returns automatically after a menu
 	/// item is selected. IMPORTANT: If a menu item callback returns `CallbackReturn::Close`,
 	/// it will ends the current popup level dialog.
@@ -73,6 +90,130 @@ pub trait DialogElement : Element + Widget + Container {
 	        _ => unreachable!(),
 	    }
 	}
+
+    /// Shows the dialog and returns a future that completes when the dialog is hidden, e.g.
+    /// when closed by the user.
+    ///
+    /// Unlike `popup` this does not block nor restrict the user interaction to the dialog, the
+    /// GUI keeps running while the future is pending. It must be used in the GUI thread, see
+    /// `task::spawn_local`. Predefined dialogs such as `FileDlg` can't be used this way.
+    ///
+    /// The future is woken from the SHOW_CB callback of the dialog, which is chained to the
+    /// callback set with `ShowCb::set_show_cb`, if any. The dialog must not be destroyed, nor
+    /// its `ShowCb` changed, while the future is pending.
+    ///
+    /// # Panics
+    /// Panics if `x` is either `Bottom` or `Top` or if `y` is either `Left` or `Right`.
+    fn popup_async(&mut self, x: DialogPos, y: DialogPos) -> PopupFuture<Self> {
+        PopupFuture { dialog: *self, pos: Some((x, y)), hook: None }
+    }
+}
+
+/// A future that shows a dialog and completes when it's hidden, see
+/// `DialogElement::popup_async`.
+pub struct PopupFuture<D: DialogElement> {
+    dialog: D,
+    /// The position to show the dialog at, `None` once shown.
+    pos: Option<(DialogPos, DialogPos)>,
+    /// The hook on the dialog SHOW_CB while the future is pending.
+    hook: Option<Box<PopupHook>>,
+}
+
+/// Wakes a `PopupFuture` when its dialog is hidden.
+struct PopupHook {
+    waker: Option<Waker>,
+    /// The SHOW_CB listener replaced by `on_popup_show`, called after it. May be null.
+    prev_show_cb: *const c_void,
+}
+
+extern "C" {
+    // Declared here with nullable pointers, since the callback of a dialog may not be set.
+    #[link_name = "IupSetCallback"]
+    fn IupSetCallbackRaw(ih: *mut iup_sys::Ihandle, name: *const libc::c_char,
+                         func: *const c_void) -> *const c_void;
+}
+
+extern "C" fn on_popup_show(ih: *mut iup_sys::Ihandle, state: c_int) -> c_int {
+    let hook = unsafe {
+        iup_sys::IupGetAttribute(ih, cstr!("_IUPRUST_POPUP_HOOK")) as *mut PopupHook
+    };
+    if hook.is_null() {
+        return iup_sys::IUP_DEFAULT;
+    }
+    let hook = unsafe { &mut *hook };
+    if state == iup_sys::IUP_HIDE {
+        if let Some(waker) = hook.waker.take() {
+            waker.wake();
+        }
+    }
+    if hook.prev_show_cb.is_null() {
+        iup_sys::IUP_DEFAULT
+    } else {
+        let prev = unsafe {
+            mem::transmute::<*const c_void, extern "C" fn(*mut iup_sys::Ihandle, c_int) -> c_int>(
+                hook.prev_show_cb)
+        };
+        prev(ih, state)
+    }
+}
+
+impl<D: DialogElement> PopupFuture<D> {
+    fn set_hook(&mut self) {
+        let ih = self.dialog.raw();
+        let mut hook = Box::new(PopupHook { waker: None, prev_show_cb: ptr::null() });
+        unsafe {
+            iup_sys::IupSetAttribute(ih, cstr!("_IUPRUST_POPUP_HOOK"),
+                                     &mut *hook as *mut PopupHook as *const _);
+            hook.prev_show_cb = IupSetCallbackRaw(ih, cstr!("SHOW_CB"),
+                                                  on_popup_show as *const c_void);
+        }
+        self.hook = Some(hook);
+    }
+
+    fn remove_hook(&mut self) {
+        if let Some(hook) = self.hook.take() {
+            let ih = self.dialog.raw();
+            unsafe {
+                IupSetCallbackRaw(ih, cstr!("SHOW_CB"), hook.prev_show_cb);
+                iup_sys::IupSetAttribute(ih, cstr!("_IUPRUST_POPUP_HOOK"), ptr::null());
+            }
+        }
+    }
+}
+
+// The dialog is just a handle and the hook is boxed, it's never pinned.
+impl<D: DialogElement> Unpin for PopupFuture<D> {}
+
+impl<D: DialogElement> Future for PopupFuture<D> {
+    type Output = Result<D, D>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<D, D>> {
+        let mut dialog = self.dialog;
+        if let Some((x, y)) = self.pos.take() {
+            self.set_hook();
+            if dialog.showxy(x, y).is_err() {
+                self.remove_hook();
+                return Poll::Ready(Err(dialog));
+            }
+        }
+
+        if dialog.visible() {
+            // Woken by `on_popup_show` when the dialog is hidden.
+            if let Some(ref mut hook) = self.hook {
+                hook.waker = Some(cx.waker().clone());
+            }
+            Poll::Pending
+        } else {
+            self.remove_hook();
+            Poll::Ready(Ok(dialog))
+        }
+    }
+}
+
+impl<D: DialogElement> Drop for PopupFuture<D> {
+    fn drop(&mut self) {
+        self.remove_hook();
+    }
 }
 
 /// The position a dialog should be positioned.
@@ -115,13 +256,15 @@ impl DialogPos {
         }
     }
 
-    fn to_raw_x(&self) -> c_int {
+    #[doc(hidden)]
+    pub fn to_raw_x(&self) -> c_int {
     	use self::DialogPos::*;
     	assert!(*self != Top && *self != Bottom);
     	self.to_raw()
     }
 
-    fn to_raw_y(&self) -> c_int {
+    #[doc(hidden)]
+    pub fn to_raw_y(&self) -> c_int {
     	use self::DialogPos::*;
     	assert!(*self != Right && *self != Left);
     	self.to_raw()
@@ -0,0 +1,578 @@
+//! Dialogs for entering a list of values.
+use iup_sys;
+use libc::{c_char, c_int, c_float, c_void};
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
+use std::path::PathBuf;
+
+use attrib::Color;
+
+/// The size of the buffers of string, file and color parameters, including the nul terminator.
+const BUFFER_SIZE: usize = 4096;
+
+/// A builder for a modal dialog asking the user for a list of values.
+///
+/// Each parameter is shown in a line with its label and an appropriate control, in the order
+/// they were added. The `add_*` methods return a typed `ParamId` to get the value of the
+/// parameter from the result:
+///
+/// ```ignore
+/// let mut dialog = ParamDialog::new("Resize");
+/// let width = dialog.add_int("Width", 1..=4096);
+/// let height = dialog.add_int("Height", 1..=4096);
+/// let keep_ratio = dialog.add_bool("Keep aspect ratio");
+/// dialog.set_value(width, 640);
+/// dialog.set_value(height, 480);
+///
+/// if let Some(values) = dialog.run() {
+///     let (width, height) = (values.get(width), values.get(height));
+/// }
+/// ```
+///
+/// The builder methods without the `add_` prefix can be chained instead, for dialogs whose
+/// values are read by position with `ParamValues::value`.
+///
+/// See the [IUP GetParam Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/dlg/iupgetparam.html
+pub struct ParamDialog {
+    title: String,
+    params: Vec<Param>,
+    validate: Option<Validate>,
+}
+
+type Validate = Box<dyn FnMut(&ParamValues) -> bool>;
+
+/// A value entered in a `ParamDialog`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParamValue {
+    Int(i32),
+    Real(f32),
+    String(String),
+    Bool(bool),
+    /// The position of the selected item in the list.
+    List(usize),
+    File(PathBuf),
+    Color(Color),
+}
+
+/// The values entered in a `ParamDialog`.
+///
+/// The values are got with the `ParamId` of their parameter, or by position in the order the
+/// parameters were added, not counting separators.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParamValues(Vec<ParamValue>);
+
+/// Identifies a parameter of a `ParamDialog` with values of type `T`, see `ParamValues::get`.
+pub struct ParamId<T> {
+    index: usize,
+    marker: PhantomData<T>,
+}
+
+/// A type of value of a `ParamDialog` parameter.
+///
+/// Lists have `usize` values, the position of the selected item.
+pub trait ParamType : Into<ParamValue> {
+    /// Gets the value if it's of this type.
+    fn from_param(value: &ParamValue) -> Option<Self>;
+}
+
+struct Param {
+    label: String,
+    kind: ParamKind,
+    value: Option<ParamValue>,
+}
+
+enum ParamKind {
+    Int(Option<(i32, i32)>),
+    Real(Option<(f32, f32)>),
+    String,
+    Bool,
+    List(Vec<String>),
+    File,
+    Color,
+    Separator,
+}
+
+/// The storage IUP writes the value of a parameter into.
+enum ParamData {
+    Int(Box<c_int>),
+    Real(Box<c_float>),
+    Text(Vec<u8>),
+}
+
+impl ParamDialog {
+    /// Creates a dialog with the specified title and no parameters.
+    pub fn new<S: Into<String>>(title: S) -> ParamDialog {
+        ParamDialog {
+            title: title.into(),
+            params: vec![],
+            validate: None,
+        }
+    }
+
+    /// Adds an integer parameter limited to the specified range, e.g. `0..1000`.
+    ///
+    /// The range is only enforced when it's bounded at both ends, use `..` for no limits. An
+    /// excluded bound with no integer past it, e.g. `..i32::MIN`, is ignored.
+    ///
+    /// # Panics
+    /// Panics if `label` contains `%` or a new line.
+    pub fn add_int<S, R>(&mut self, label: S, range: R) -> ParamId<i32>
+                                        where S: Into<String>, R: RangeBounds<i32> {
+        let min = match range.start_bound() {
+            Bound::Included(&min) => Some(min),
+            Bound::Excluded(&min) => min.checked_add(1),
+            Bound::Unbounded => None,
+        };
+        let max = match range.end_bound() {
+            Bound::Included(&max) => Some(max),
+            Bound::Excluded(&max) => max.checked_sub(1),
+            Bound::Unbounded => None,
+        };
+        let range = min.and_then(|min| max.map(|max| (min, max)));
+        self.add_param(label, ParamKind::Int(range), range.map(|(min, _)| ParamValue::Int(min)))
+    }
+
+    /// Adds a real parameter limited to the specified range, e.g. `0.0..=1.0`.
+    ///
+    /// Both ends of the range are inclusive, and the range is only enforced when it's bounded
+    /// at both ends, use `..` for no limits.
+    ///
+    /// # Panics
+    /// Panics if `label` contains `%` or a new line.
+    pub fn add_real<S, R>(&mut self, label: S, range: R) -> ParamId<f32>
+                                        where S: Into<String>, R: RangeBounds<f32> {
+        let min = match range.start_bound() {
+            Bound::Included(&min) | Bound::Excluded(&min) => Some(min),
+            Bound::Unbounded => None,
+        };
+        let max = match range.end_bound() {
+            Bound::Included(&max) | Bound::Excluded(&max) => Some(max),
+            Bound::Unbounded => None,
+        };
+        let range = min.and_then(|min| max.map(|max| (min, max)));
+        self.add_param(label, ParamKind::Real(range), range.map(|(min, _)| ParamValue::Real(min)))
+    }
+
+    /// Adds a single line text parameter.
+    ///
+    /// # Panics
+    /// Panics if `label` contains `%` or a new line.
+    pub fn add_string<S: Into<String>>(&mut self, label: S) -> ParamId<String> {
+        self.add_param(label, ParamKind::String, None)
+    }
+
+    /// Adds a boolean parameter shown as a check box.
+    ///
+    /// # Panics
+    /// Panics if `label` contains `%` or a new line.
+    pub fn add_bool<S: Into<String>>(&mut self, label: S) -> ParamId<bool> {
+        self.add_param(label, ParamKind::Bool, None)
+    }
+
+    /// Adds a parameter to select one of the specified items, shown as a drop down list.
+    ///
+    /// The value is the position of the selected item, starting at 0.
+    ///
+    /// # Panics
+    /// Panics if `label` contains `%` or a new line, or if an item contains `|` or a new line.
+    pub fn add_list<S, U, I>(&mut self, label: S, items: U) -> ParamId<usize>
+                                        where S: Into<String>, U: AsRef<[I]>, I: AsRef<str> {
+        let items = items.as_ref().iter().map(|item| {
+            let item = item.as_ref();
+            assert!(!item.contains('|') && !item.contains('\n'),
+                    "list items can't contain `|` or new lines");
+            item.to_owned()
+        }).collect();
+        self.add_param(label, ParamKind::List(items), None)
+    }
+
+    /// Adds a file name parameter, with a button to select the file with a `FileDlg`.
+    ///
+    /// # Panics
+    /// Panics if `label` contains `%` or a new line.
+    pub fn add_file<S: Into<String>>(&mut self, label: S) -> ParamId<PathBuf> {
+        self.add_param(label, ParamKind::File, None)
+    }
+
+    /// Adds a color parameter, with a button to select the color with a `ColorDlg`.
+    ///
+    /// # Panics
+    /// Panics if `label` contains `%` or a new line.
+    pub fn add_color<S: Into<String>>(&mut self, label: S) -> ParamId<Color> {
+        self.add_param(label, ParamKind::Color, None)
+    }
+
+    /// Adds a separator with the specified label to group the following parameters.
+    ///
+    /// # Panics
+    /// Panics if `label` contains `%` or a new line.
+    pub fn add_separator<S: Into<String>>(&mut self, label: S) {
+        self.add_param::<_, ()>(label, ParamKind::Separator, None);
+    }
+
+    /// Sets the initial value of a parameter.
+    ///
+    /// # Panics
+    /// Panics if the parameter is not from this dialog.
+    pub fn set_value<T: ParamType>(&mut self, id: ParamId<T>, value: T) {
+        let param = self.params.iter_mut().filter(|param| !param.is_separator())
+                                .nth(id.index).expect("parameter not found");
+        param.value = Some(value.into());
+    }
+
+    /// Adds an integer parameter, as `add_int` but for chaining.
+    pub fn int<S, R>(mut self, label: S, range: R) -> ParamDialog
+                                        where S: Into<String>, R: RangeBounds<i32> {
+        self.add_int(label, range);
+        self
+    }
+
+    /// Adds a real parameter, as `add_real` but for chaining.
+    pub fn real<S, R>(mut self, label: S, range: R) -> ParamDialog
+                                        where S: Into<String>, R: RangeBounds<f32> {
+        self.add_real(label, range);
+        self
+    }
+
+    /// Adds a single line text parameter, as `add_string` but for chaining.
+    pub fn string<S: Into<String>>(mut self, label: S) -> ParamDialog {
+        self.add_string(label);
+        self
+    }
+
+    /// Adds a boolean parameter, as `add_bool` but for chaining.
+    pub fn bool<S: Into<String>>(mut self, label: S) -> ParamDialog {
+        self.add_bool(label);
+        self
+    }
+
+    /// Adds a list parameter, as `add_list` but for chaining.
+    pub fn list<S, U, I>(mut self, label: S, items: U) -> ParamDialog
+                                        where S: Into<String>, U: AsRef<[I]>, I: AsRef<str> {
+        self.add_list(label, items);
+        self
+    }
+
+    /// Adds a file name parameter, as `add_file` but for chaining.
+    pub fn file<S: Into<String>>(mut self, label: S) -> ParamDialog {
+        self.add_file(label);
+        self
+    }
+
+    /// Adds a color parameter, as `add_color` but for chaining.
+    pub fn color<S: Into<String>>(mut self, label: S) -> ParamDialog {
+        self.add_color(label);
+        self
+    }
+
+    /// Adds a separator, as `add_separator` but for chaining.
+    pub fn separator<S: Into<String>>(mut self, label: S) -> ParamDialog {
+        self.add_separator(label);
+        self
+    }
+
+    /// Sets the initial value of the last added parameter.
+    ///
+    /// # Panics
+    /// Panics if there are no parameters or if the value does not match the kind of the
+    /// last parameter.
+    pub fn value<V: Into<ParamValue>>(mut self, value: V) -> ParamDialog {
+        let value = value.into();
+        {
+            let param = self.params.last_mut().expect("no parameter to set the value of");
+            let matches = matches!((&param.kind, &value),
+                (&ParamKind::Int(_), &ParamValue::Int(_)) |
+                (&ParamKind::Real(_), &ParamValue::Real(_)) |
+                (&ParamKind::String, &ParamValue::String(_)) |
+                (&ParamKind::Bool, &ParamValue::Bool(_)) |
+                (&ParamKind::List(_), &ParamValue::List(_)) |
+                (&ParamKind::File, &ParamValue::File(_)) |
+                (&ParamKind::Color, &ParamValue::Color(_)));
+            assert!(matches, "value does not match the kind of the parameter");
+            param.value = Some(value);
+        }
+        self
+    }
+
+    fn add_param<S: Into<String>, T>(&mut self, label: S, kind: ParamKind,
+                                     value: Option<ParamValue>) -> ParamId<T> {
+        let label = label.into();
+        assert!(!label.contains('%') && !label.contains('\n'),
+                "parameter labels can't contain `%` or new lines");
+        let index = self.params.iter().filter(|param| !param.is_separator()).count();
+        self.params.push(Param { label, kind, value });
+        ParamId { index, marker: PhantomData }
+    }
+
+    /// Sets a function to validate the values when the user presses OK.
+    ///
+    /// If the function returns `false` the dialog is kept open.
+    pub fn validate<F>(mut self, validate: F) -> ParamDialog
+                                        where F: FnMut(&ParamValues) -> bool + 'static {
+        self.validate = Some(Box::new(validate));
+        self
+    }
+
+    /// Shows the dialog and returns the entered values, or `None` if the dialog was cancelled.
+    ///
+    /// # Panics
+    /// Panics if there are no parameters other than separators.
+    pub fn run(&mut self) -> Option<ParamValues> {
+        let format = self.params.iter().map(|param| param.format()).collect::<String>();
+        let mut data = self.params.iter().filter_map(|param| param.data()).collect::<Vec<_>>();
+        let mut data_ptrs = data.iter_mut().map(|data| data.as_mut_ptr()).collect::<Vec<_>>();
+        assert!(!data.is_empty(), "a parameter dialog must have parameters");
+
+        let ctitle = CString::new(&*self.title).unwrap();
+        let cformat = CString::new(format).unwrap();
+        let count = data.len() as c_int;
+        let extra = self.params.len() as c_int - count;
+
+        let result = unsafe {
+            let user_data = self as *mut ParamDialog as *mut c_void;
+            iup_sys::IupGetParamv(ctitle.as_ptr(), param_action, user_data, cformat.as_ptr(),
+                                  count, extra, data_ptrs.as_mut_ptr())
+        };
+
+        if result != 1 {
+            return None;
+        }
+
+        let params = self.params.iter().filter(|param| !param.is_separator());
+        Some(ParamValues(params.zip(data.iter()).map(|(param, data)| param.kind.value(&data.text()))
+                                                .collect()))
+    }
+}
+
+impl Param {
+    fn is_separator(&self) -> bool {
+        matches!(self.kind, ParamKind::Separator)
+    }
+
+    fn format(&self) -> String {
+        let spec = match self.kind {
+            ParamKind::Int(Some((min, max))) => format!("%i[{},{}]", min, max),
+            ParamKind::Int(None) => "%i".to_owned(),
+            ParamKind::Real(Some((min, max))) => format!("%r[{},{}]", min, max),
+            ParamKind::Real(None) => "%r".to_owned(),
+            ParamKind::String => "%s".to_owned(),
+            ParamKind::Bool => "%b".to_owned(),
+            ParamKind::List(ref items) => format!("%l|{}|", items.join("|")),
+            ParamKind::File => "%f".to_owned(),
+            ParamKind::Color => "%c".to_owned(),
+            ParamKind::Separator => "%t".to_owned(),
+        };
+        format!("{}: {}\n", self.label, spec)
+    }
+
+    /// Creates the storage of the parameter with its initial value, `None` for separators.
+    fn data(&self) -> Option<ParamData> {
+        let text = |s: String| {
+            let mut buf = s.into_bytes();
+            buf.truncate(BUFFER_SIZE - 1);
+            buf.resize(BUFFER_SIZE, 0);
+            ParamData::Text(buf)
+        };
+        Some(match (&self.kind, &self.value) {
+            (&ParamKind::Separator, _) => return None,
+            (&ParamKind::Int(_), &Some(ParamValue::Int(v))) => ParamData::Int(Box::new(v)),
+            (&ParamKind::Int(_), _) => ParamData::Int(Box::new(0)),
+            (&ParamKind::Real(_), &Some(ParamValue::Real(v))) => ParamData::Real(Box::new(v)),
+            (&ParamKind::Real(_), _) => ParamData::Real(Box::new(0.0)),
+            (&ParamKind::Bool, &Some(ParamValue::Bool(v))) => ParamData::Int(Box::new(v as c_int)),
+            (&ParamKind::List(_), &Some(ParamValue::List(v))) => ParamData::Int(Box::new(v as c_int)),
+            (&ParamKind::Bool, _) | (&ParamKind::List(_), _) => ParamData::Int(Box::new(0)),
+            (_, &Some(ParamValue::String(ref s))) => text(s.clone()),
+            (_, &Some(ParamValue::File(ref path))) => text(path.to_string_lossy().into_owned()),
+            (_, &Some(ParamValue::Color(color))) => text(color.to_string()),
+            (&ParamKind::Color, _) => text(Color::BLACK.to_string()),
+            (_, _) => text(String::new()),
+        })
+    }
+}
+
+impl ParamKind {
+    /// Converts the value of a parameter from its string format, the format of the VALUE
+    /// attribute of the parameter controls.
+    fn value(&self, s: &str) -> ParamValue {
+        match *self {
+            ParamKind::Int(_) => ParamValue::Int(s.trim().parse().unwrap_or(0)),
+            ParamKind::Real(_) => ParamValue::Real(s.trim().parse().unwrap_or(0.0)),
+            ParamKind::String => ParamValue::String(s.to_owned()),
+            ParamKind::Bool => ParamValue::Bool(s.trim() == "1"),
+            ParamKind::List(_) => ParamValue::List(s.trim().parse().unwrap_or(0)),
+            ParamKind::File => ParamValue::File(PathBuf::from(s)),
+            ParamKind::Color => ParamValue::Color(s.parse().unwrap_or(Color::BLACK)),
+            ParamKind::Separator => unreachable!(),
+        }
+    }
+}
+
+impl ParamData {
+    fn as_mut_ptr(&mut self) -> *mut c_void {
+        match *self {
+            ParamData::Int(ref mut v) => &mut **v as *mut c_int as *mut c_void,
+            ParamData::Real(ref mut v) => &mut **v as *mut c_float as *mut c_void,
+            ParamData::Text(ref mut buf) => buf.as_mut_ptr() as *mut c_void,
+        }
+    }
+
+    fn text(&self) -> String {
+        match *self {
+            ParamData::Int(ref v) => v.to_string(),
+            ParamData::Real(ref v) => v.to_string(),
+            ParamData::Text(ref buf) => {
+                let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+                String::from_utf8_lossy(&buf[..len]).into_owned()
+            },
+        }
+    }
+}
+
+/// Calls the validation function of the `ParamDialog` in `user_data` when OK is pressed.
+extern "C" fn param_action(dialog: *mut iup_sys::Ihandle, param_index: c_int,
+                           user_data: *mut c_void) -> c_int {
+    if param_index != iup_sys::IUP_GETPARAM_OK {
+        return 1;
+    }
+
+    let this = unsafe { &mut *(user_data as *mut ParamDialog) };
+    let ParamDialog { ref params, ref mut validate, .. } = *this;
+    let validate = match *validate {
+        Some(ref mut validate) => validate,
+        None => return 1,
+    };
+
+    // The values are not stored yet, get them from the controls of the dialog.
+    let values = params.iter().enumerate()
+                       .filter(|&(_, param)| !param.is_separator())
+                       .map(|(i, param)| param.kind.value(&param_control_value(dialog, i)))
+                       .collect();
+
+    if validate(&ParamValues(values)) { 1 } else { 0 }
+}
+
+/// Gets the VALUE attribute of the control of the parameter at `index`.
+fn param_control_value(dialog: *mut iup_sys::Ihandle, index: usize) -> String {
+    let name = CString::new(format!("PARAM{}", index)).unwrap();
+    unsafe {
+        let param = iup_sys::IupGetAttribute(dialog, name.as_ptr()) as *mut iup_sys::Ihandle;
+        if param.is_null() {
+            return String::new();
+        }
+        let value: *const c_char = iup_sys::IupGetAttribute(param, cstr!("VALUE"));
+        if value.is_null() {
+            String::new()
+        } else {
+            CStr::from_ptr(value).to_string_lossy().into_owned()
+        }
+    }
+}
+
+impl ParamValues {
+    /// Gets the value of a parameter.
+    ///
+    /// # Panics
+    /// Panics if the parameter is not from the dialog of these values.
+    pub fn get<T: ParamType>(&self, id: ParamId<T>) -> T {
+        self.0.get(id.index).and_then(T::from_param).expect("parameter not found")
+    }
+
+    /// Gets the value at `index`.
+    pub fn value(&self, index: usize) -> Option<&ParamValue> {
+        self.0.get(index)
+    }
+
+    /// Gets the number of values.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether there are no values.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl IntoIterator for ParamValues {
+    type Item = ParamValue;
+    type IntoIter = ::std::vec::IntoIter<ParamValue>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl From<i32> for ParamValue {
+    fn from(v: i32) -> ParamValue { ParamValue::Int(v) }
+}
+
+impl From<f32> for ParamValue {
+    fn from(v: f32) -> ParamValue { ParamValue::Real(v) }
+}
+
+impl From<String> for ParamValue {
+    fn from(v: String) -> ParamValue { ParamValue::String(v) }
+}
+
+impl<'a> From<&'a str> for ParamValue {
+    fn from(v: &'a str) -> ParamValue { ParamValue::String(v.to_owned()) }
+}
+
+impl From<bool> for ParamValue {
+    fn from(v: bool) -> ParamValue { ParamValue::Bool(v) }
+}
+
+impl From<usize> for ParamValue {
+    fn from(v: usize) -> ParamValue { ParamValue::List(v) }
+}
+
+impl From<PathBuf> for ParamValue {
+    fn from(v: PathBuf) -> ParamValue { ParamValue::File(v) }
+}
+
+impl From<Color> for ParamValue {
+    fn from(v: Color) -> ParamValue { ParamValue::Color(v) }
+}
+
+impl<T> Clone for ParamId<T> {
+    fn clone(&self) -> ParamId<T> {
+        *self
+    }
+}
+
+impl<T> Copy for ParamId<T> {}
+
+impl<T> PartialEq for ParamId<T> {
+    fn eq(&self, other: &ParamId<T>) -> bool {
+        self.index == other.index
+    }
+}
+
+impl<T> Eq for ParamId<T> {}
+
+impl<T> fmt::Debug for ParamId<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_tuple("ParamId").field(&self.index).finish()
+    }
+}
+
+macro_rules! impl_param_type {
+    ($($ty:ty => $variant:ident),*) => {
+        $(
+            impl ParamType for $ty {
+                fn from_param(value: &ParamValue) -> Option<$ty> {
+                    match *value {
+                        ParamValue::$variant(ref v) => Some(v.clone()),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    }
+}
+
+impl_param_type!(i32 => Int, f32 => Real, String => String, bool => Bool, usize => List,
+                 PathBuf => File, Color => Color);
@@ -0,0 +1,104 @@
+use iup_sys;
+
+use Element;
+
+/// A predefined dialog showing the progress of a long operation, with a button to cancel it.
+///
+/// The progress is defined by a total count and the current count. The operation should
+/// update the count and check `ProgressDlg::state` periodically, e.g. with `Widget::show`
+/// and `ProgressDlg::inc` between steps of the work.
+///
+/// See the [IUP ProgressDlg Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/dlg/iupprogressdlg.html
+pub struct ProgressDlg(*mut iup_sys::Ihandle);
+
+impl ProgressDlg {
+    /// Creates a progress dialog.
+    pub fn new() -> ProgressDlg {
+        unsafe { ProgressDlg::from_raw(iup_sys::IupProgressDlg()) }
+    }
+
+    impl_attribs! {
+        /// The text of the dialog title bar.
+        pub fn title, set_title: String = "TITLE";
+
+        /// The text shown above the progress bar, describing the operation.
+        pub fn description, set_description: String = "DESCRIPTION";
+
+        /// The total count of steps of the operation. Default `100`.
+        pub fn total_count, set_total_count: u32 = "TOTALCOUNT", default 100;
+
+        /// The current count of steps done. Default `0`.
+        pub fn count, set_count: u32 = "COUNT", default 0;
+
+        /// The progress in percent, calculated from the counts.
+        pub fn percent, set_percent: u32 = "PERCENT", default 0;
+    }
+
+    /// Increments the current count by the specified number of steps.
+    pub fn inc(&mut self, steps: u32) -> Self {
+        self.set_attrib("INC", steps.to_string())
+    }
+
+    /// Gets the state of the operation.
+    pub fn state(&self) -> ProgressState {
+        match self.attrib("STATE") {
+            Some(ref s) if s == "PROCESSING" => ProgressState::Processing,
+            Some(ref s) if s == "UNDEFINED" => ProgressState::Undefined,
+            Some(ref s) if s == "ABORTED" => ProgressState::Aborted,
+            _ => ProgressState::Idle,
+        }
+    }
+
+    /// Sets the state of the operation.
+    ///
+    /// Setting `ProgressState::Undefined` shows a marquee instead of the progress.
+    pub fn set_state(&mut self, state: ProgressState) -> Self {
+        self.set_attrib("STATE", state.as_str())
+    }
+}
+
+impl_dialog!(ProgressDlg, "progressdlg");
+impl ::callback::MapCb for ProgressDlg {}
+impl ::callback::UnmapCb for ProgressDlg {}
+impl ::callback::HelpCb for ProgressDlg {}
+
+/// See the `CancelCb` documentation.
+impl self::CancelCb for ProgressDlg {}
+
+/// The state of the operation of a `ProgressDlg`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ProgressState {
+    /// The operation is not running.
+    Idle,
+    /// The operation is running.
+    Processing,
+    /// The operation is running with an undefined progress.
+    Undefined,
+    /// The user pressed the cancel button.
+    Aborted,
+}
+
+impl ProgressState {
+    fn as_str(self) -> &'static str {
+        match self {
+            ProgressState::Idle => "IDLE",
+            ProgressState::Processing => "PROCESSING",
+            ProgressState::Undefined => "UNDEFINED",
+            ProgressState::Aborted => "ABORTED",
+        }
+    }
+}
+
+impl_callback! {
+    #[doc="Called when the user presses the cancel button of a `ProgressDlg`."]
+    #[doc=""]
+    #[doc="By default the state is changed to `ProgressState::Aborted`. If"]
+    #[doc="`CallbackReturn::Continue` is returned the state is not changed."]
+    pub trait CancelCb where Self: Element {
+        let name = "CANCEL_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle) -> CallbackReturn;
+        fn set_cancel_cb<F: Callback(Self)>(&mut self, cb: F) -> Self;
+        fn remove_cancel_cb(&mut self) -> Option<Box<_>>;
+    }
+}
@@ -0,0 +1,34 @@
+use libc::{c_char, c_int};
+use std::ffi::CString;
+
+/// The size of the buffer of `get_text`, including the nul terminator.
+const GET_TEXT_SIZE: usize = 10240;
+
+// The iup-sys binding lacks the `maxsize` parameter of the IUP versions targeted by this crate,
+// without it IUP would read the buffer size from garbage.
+extern "C" {
+    fn IupGetText(title: *const c_char, text: *mut c_char, maxsize: c_int) -> c_int;
+}
+
+/// Shows a modal dialog to edit a multiline text.
+///
+/// Returns the edited text or `None` if the dialog was cancelled. The text is limited to
+/// 10239 bytes.
+///
+/// See the [IUP GetText Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/dlg/iupgettext.html
+pub fn get_text<S1, S2>(title: S1, initial: S2) -> Option<String>
+                where S1: Into<String>, S2: Into<String> {
+    let ctitle = CString::new(title.into()).unwrap();
+    let mut buf = initial.into().into_bytes();
+    buf.truncate(GET_TEXT_SIZE - 1);
+    buf.resize(GET_TEXT_SIZE, 0);
+    match unsafe { IupGetText(ctitle.as_ptr(), buf.as_mut_ptr() as *mut c_char,
+                                GET_TEXT_SIZE as c_int) } {
+        1 => {
+            let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+            Some(String::from_utf8_lossy(&buf[..len]).into_owned())
+        },
+        _ => None,
+    }
+}
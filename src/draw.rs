@@ -0,0 +1,312 @@
+//! Drawing primitives for canvases.
+//!
+//! A `DrawContext` is obtained from a `Canvas`, usually inside its `CanvasAction` (redraw)
+//! callback, and guarantees the drawing is flushed to the screen when it gets out of scope.
+//!
+//! All coordinates are in pixels, relative to the top-left corner of the canvas, with the y
+//! axis pointing down. The attributes of the primitives (color, style, font, etc) are stored in
+//! the canvas and apply to every primitive drawn after they are set.
+//!
+//! # Example
+//! ```ignore
+//! Canvas::new().set_action(|(mut canvas, _, _)| {
+//!     let mut dc = canvas.draw();
+//!     let (w, h) = dc.size();
+//!     dc.set_color((255, 255, 255))
+//!       .set_style(DrawStyle::Fill)
+//!       .rectangle(0, 0, w - 1, h - 1);
+//!     dc.set_color((255, 0, 0))
+//!       .set_style(DrawStyle::Stroke)
+//!       .line(0, 0, w - 1, h - 1);
+//! });
+//! ```
+//!
+//! See the [IUP Drawing Functions Documentation][1].
+//! [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/func/iupdraw.html
+use iup_sys;
+use libc::{c_char, c_int, c_double};
+use std::ffi::CString;
+
+use Element;
+use control::Canvas;
+use image::ImageElement;
+use attrib::{Color, Font};
+
+// The drawing functions are not part of the iup-sys bindings, the signatures used here (with
+// the `w` and `h` parameters of `IupDrawText` and `IupDrawImage`) are from IUP 3.25.
+extern "C" {
+    fn IupDrawBegin(ih: *mut iup_sys::Ihandle);
+    fn IupDrawEnd(ih: *mut iup_sys::Ihandle);
+    fn IupDrawSetClipRect(ih: *mut iup_sys::Ihandle, x1: c_int, y1: c_int, x2: c_int, y2: c_int);
+    fn IupDrawResetClip(ih: *mut iup_sys::Ihandle);
+    fn IupDrawParentBackground(ih: *mut iup_sys::Ihandle);
+    fn IupDrawLine(ih: *mut iup_sys::Ihandle, x1: c_int, y1: c_int, x2: c_int, y2: c_int);
+    fn IupDrawRectangle(ih: *mut iup_sys::Ihandle, x1: c_int, y1: c_int, x2: c_int, y2: c_int);
+    fn IupDrawArc(ih: *mut iup_sys::Ihandle, x1: c_int, y1: c_int, x2: c_int, y2: c_int,
+                  a1: c_double, a2: c_double);
+    fn IupDrawPolygon(ih: *mut iup_sys::Ihandle, points: *const c_int, count: c_int);
+    fn IupDrawText(ih: *mut iup_sys::Ihandle, text: *const c_char, len: c_int,
+                   x: c_int, y: c_int, w: c_int, h: c_int);
+    fn IupDrawImage(ih: *mut iup_sys::Ihandle, name: *const c_char,
+                    x: c_int, y: c_int, w: c_int, h: c_int);
+    fn IupDrawSelectRect(ih: *mut iup_sys::Ihandle, x1: c_int, y1: c_int, x2: c_int, y2: c_int);
+    fn IupDrawFocusRect(ih: *mut iup_sys::Ihandle, x1: c_int, y1: c_int, x2: c_int, y2: c_int);
+    fn IupDrawGetSize(ih: *mut iup_sys::Ihandle, w: *mut c_int, h: *mut c_int);
+    fn IupDrawGetTextSize(ih: *mut iup_sys::Ihandle, text: *const c_char, len: c_int,
+                          w: *mut c_int, h: *mut c_int);
+}
+
+/// How lines and shapes are drawn.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DrawStyle {
+    /// Shapes are filled, lines are drawn solid.
+    Fill,
+    Stroke,
+    StrokeDash,
+    StrokeDot,
+    StrokeDashDot,
+    StrokeDashDotDot,
+}
+
+impl DrawStyle {
+    fn as_str(self) -> &'static str {
+        match self {
+            DrawStyle::Fill => "FILL",
+            DrawStyle::Stroke => "STROKE",
+            DrawStyle::StrokeDash => "STROKE_DASH",
+            DrawStyle::StrokeDot => "STROKE_DOT",
+            DrawStyle::StrokeDashDot => "STROKE_DASH_DOT",
+            DrawStyle::StrokeDashDotDot => "STROKE_DASH_DOT_DOT",
+        }
+    }
+}
+
+/// Horizontal alignment of text drawn inside a rectangle.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TextAlign {
+    Left,
+    Center,
+    Right,
+}
+
+impl TextAlign {
+    fn as_str(self) -> &'static str {
+        match self {
+            TextAlign::Left => "ALEFT",
+            TextAlign::Center => "ACENTER",
+            TextAlign::Right => "ARIGHT",
+        }
+    }
+}
+
+/// A drawing session on a canvas.
+///
+/// The drawing is performed on an off-screen buffer which is flushed to the screen when the
+/// context is dropped, thus nothing is shown until then.
+///
+/// Only one context should exist for a canvas at a time, and it should only be created while
+/// the canvas is mapped, usually during the `CanvasAction` callback.
+#[derive(Debug)]
+pub struct DrawContext<'a> {
+    canvas: &'a mut Canvas,
+}
+
+impl<'a> DrawContext<'a> {
+    /// Begins drawing on the specified canvas.
+    pub fn new(canvas: &'a mut Canvas) -> DrawContext<'a> {
+        unsafe { IupDrawBegin(canvas.raw()) };
+        DrawContext { canvas }
+    }
+
+    /// Gets the size of the drawing area in pixels.
+    pub fn size(&self) -> (i32, i32) {
+        let (mut w, mut h): (c_int, c_int) = (0, 0);
+        unsafe { IupDrawGetSize(self.canvas.raw(), &mut w, &mut h) };
+        (w, h)
+    }
+
+    /// Sets the color of the next primitives. Default black.
+    pub fn set_color<C: Into<Color>>(&mut self, color: C) -> &mut Self {
+        self.canvas.set_attrib_value("DRAWCOLOR", &color.into());
+        self
+    }
+
+    /// Sets the style of the next primitives. Default `DrawStyle::Stroke`.
+    pub fn set_style(&mut self, style: DrawStyle) -> &mut Self {
+        self.canvas.set_attrib("DRAWSTYLE", style.as_str());
+        self
+    }
+
+    /// Sets the width of lines in pixels. Default 1.
+    pub fn set_line_width(&mut self, width: u32) -> &mut Self {
+        self.canvas.set_attrib("DRAWLINEWIDTH", width.to_string());
+        self
+    }
+
+    /// Sets the font used for text.
+    ///
+    /// Defaults to the FONT attribute of the canvas.
+    pub fn set_font(&mut self, font: &Font) -> &mut Self {
+        self.canvas.set_attrib_value("DRAWFONT", font);
+        self
+    }
+
+    /// Sets the alignment of text drawn with `DrawContext::text_in`. Default `TextAlign::Left`.
+    pub fn set_text_alignment(&mut self, align: TextAlign) -> &mut Self {
+        self.canvas.set_attrib("DRAWTEXTALIGNMENT", align.as_str());
+        self
+    }
+
+    /// Sets whether text drawn with `DrawContext::text_in` is wrapped into multiple lines.
+    pub fn set_text_wrap(&mut self, wrap: bool) -> &mut Self {
+        self.canvas.set_attrib("DRAWTEXTWRAP", if wrap { "YES" } else { "NO" });
+        self
+    }
+
+    /// Sets whether text drawn with `DrawContext::text_in` is ended with an ellipsis
+    /// when it does not fit.
+    pub fn set_text_ellipsis(&mut self, ellipsis: bool) -> &mut Self {
+        self.canvas.set_attrib("DRAWTEXTELLIPSIS", if ellipsis { "YES" } else { "NO" });
+        self
+    }
+
+    /// Sets whether text drawn with `DrawContext::text_in` is clipped to its rectangle.
+    pub fn set_text_clip(&mut self, clip: bool) -> &mut Self {
+        self.canvas.set_attrib("DRAWTEXTCLIP", if clip { "YES" } else { "NO" });
+        self
+    }
+
+    /// Sets the counter-clockwise rotation of text in degrees. Default 0.
+    pub fn set_text_orientation(&mut self, degrees: f64) -> &mut Self {
+        self.canvas.set_attrib("DRAWTEXTORIENTATION", degrees.to_string());
+        self
+    }
+
+    /// Fills the canvas with the background of its parent.
+    pub fn parent_background(&mut self) -> &mut Self {
+        unsafe { IupDrawParentBackground(self.canvas.raw()) };
+        self
+    }
+
+    /// Draws a line including start and end points.
+    pub fn line(&mut self, x1: i32, y1: i32, x2: i32, y2: i32) -> &mut Self {
+        unsafe { IupDrawLine(self.canvas.raw(), x1, y1, x2, y2) };
+        self
+    }
+
+    /// Draws a rectangle including start and end points.
+    pub fn rectangle(&mut self, x1: i32, y1: i32, x2: i32, y2: i32) -> &mut Self {
+        unsafe { IupDrawRectangle(self.canvas.raw(), x1, y1, x2, y2) };
+        self
+    }
+
+    /// Draws an arc inside the rectangle `(x1, y1)-(x2, y2)`.
+    ///
+    /// The angles `a1` and `a2` are in degrees, counter-clockwise starting at 3 o'clock.
+    /// A full ellipse is drawn with `0.0` and `360.0`.
+    pub fn arc(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, a1: f64, a2: f64) -> &mut Self {
+        unsafe { IupDrawArc(self.canvas.raw(), x1, y1, x2, y2, a1, a2) };
+        self
+    }
+
+    /// Draws a polygon from the specified points.
+    ///
+    /// The polygon is closed only when the style is `DrawStyle::Fill`, otherwise repeat the
+    /// first point at the end to close it.
+    pub fn polygon<U>(&mut self, points: U) -> &mut Self where U: AsRef<[(i32, i32)]> {
+        let points = points.as_ref();
+        let mut flat = Vec::with_capacity(points.len() * 2);
+        for &(x, y) in points {
+            flat.push(x as c_int);
+            flat.push(y as c_int);
+        }
+        unsafe { IupDrawPolygon(self.canvas.raw(), flat.as_ptr(), points.len() as c_int) };
+        self
+    }
+
+    /// Draws a text with its top-left corner at the specified position.
+    ///
+    /// The text can have multiple lines separated by `\n`. Nul characters are not drawn.
+    pub fn text<S: Into<String>>(&mut self, text: S, x: i32, y: i32) -> &mut Self {
+        self.text_in(text, x, y, 0, 0)
+    }
+
+    /// Draws a text inside the rectangle at the specified position and size.
+    ///
+    /// The text is aligned, wrapped, clipped and ellipsized inside the rectangle according to
+    /// the text settings of the context. If `w` or `h` are zero the text size is used. Nul
+    /// characters are not drawn.
+    pub fn text_in<S: Into<String>>(&mut self, text: S, x: i32, y: i32, w: i32, h: i32) -> &mut Self {
+        let ctext = to_cstring(text.into());
+        let len = ctext.as_bytes().len() as c_int;
+        unsafe { IupDrawText(self.canvas.raw(), ctext.as_ptr(), len, x, y, w, h) };
+        self
+    }
+
+    /// Draws an image with its top-left corner at the specified position.
+    ///
+    /// Images with no handle name receive a temporary one while drawing, since the drawing
+    /// functions find images by name.
+    pub fn image<I: ImageElement>(&mut self, image: &I, x: i32, y: i32) -> &mut Self {
+        let (name, temporary) = match image.handle_name() {
+            Some(name) => (name, false),
+            None => {
+                let name = format!("_IUPRUST_DRAWIMAGE_{:p}", image.raw());
+                image.add_handle_name(name.clone());
+                (name, true)
+            },
+        };
+        let cname = to_cstring(name.clone());
+        unsafe { IupDrawImage(self.canvas.raw(), cname.as_ptr(), x, y, -1, -1) };
+        if temporary {
+            I::clear_handle_name(name);
+        }
+        self
+    }
+
+    /// Draws a selection rectangle.
+    pub fn select_rect(&mut self, x1: i32, y1: i32, x2: i32, y2: i32) -> &mut Self {
+        unsafe { IupDrawSelectRect(self.canvas.raw(), x1, y1, x2, y2) };
+        self
+    }
+
+    /// Draws a focus rectangle.
+    pub fn focus_rect(&mut self, x1: i32, y1: i32, x2: i32, y2: i32) -> &mut Self {
+        unsafe { IupDrawFocusRect(self.canvas.raw(), x1, y1, x2, y2) };
+        self
+    }
+
+    /// Restricts the next primitives to the specified rectangle.
+    pub fn set_clip_rect(&mut self, x1: i32, y1: i32, x2: i32, y2: i32) -> &mut Self {
+        unsafe { IupDrawSetClipRect(self.canvas.raw(), x1, y1, x2, y2) };
+        self
+    }
+
+    /// Removes the clipping rectangle.
+    pub fn reset_clip(&mut self) -> &mut Self {
+        unsafe { IupDrawResetClip(self.canvas.raw()) };
+        self
+    }
+
+    /// Measures the size of a text in pixels using the current font, ignoring nul characters.
+    pub fn text_size<S: Into<String>>(&self, text: S) -> (i32, i32) {
+        let ctext = to_cstring(text.into());
+        let len = ctext.as_bytes().len() as c_int;
+        let (mut w, mut h): (c_int, c_int) = (0, 0);
+        unsafe { IupDrawGetTextSize(self.canvas.raw(), ctext.as_ptr(), len, &mut w, &mut h) };
+        (w, h)
+    }
+}
+
+/// Converts a string for the drawing functions, removing any nul characters so that a bad string
+/// can't make a redraw panic.
+fn to_cstring(mut s: String) -> CString {
+    s.retain(|c| c != '\0');
+    CString::new(s).unwrap()
+}
+
+impl<'a> Drop for DrawContext<'a> {
+    fn drop(&mut self) {
+        unsafe { IupDrawEnd(self.canvas.raw()) };
+    }
+}
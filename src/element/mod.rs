@@ -9,6 +9,8 @@ use std::result::Result;
 use std::iter::repeat;
 use std::str::FromStr;
 
+use attrib::AttribValue;
+
 pub mod guard;
 pub use self::guard::Guard;
 
@@ -118,11 +120,21 @@ impl Handle {
     }
 
     /// Checks if this Element type can be downcasted to the type E.
+    ///
+    /// Types for a variant of an IUP class use a `"class:ATTRIB"` target classname, and only
+    /// elements with the boolean attribute enabled are compatible, e.g. `"text:SPIN"`.
     fn can_downcast<E: Element>(&self) -> bool {
-        let lhs = unsafe { self.classname().to_bytes() };
-        let rhs = unsafe { E::target_classname().as_bytes() };
-        lhs == rhs || rhs == b"__iuprusthandle"
-        // In case self/lhs (a Handle) is trying to cast to a target object of Handle, let it go.
+        let target = unsafe { E::target_classname() };
+        if target == "__iuprusthandle" {
+            // In case self (a Handle) is trying to cast to a target object of Handle, let it go.
+            return true;
+        }
+        let mut parts = target.splitn(2, ':');
+        let classname = parts.next().unwrap_or("");
+        if unsafe { self.classname().to_bytes() } != classname.as_bytes() {
+            return false;
+        }
+        parts.next().is_none_or(|attrib| self.attrib_bool(attrib).unwrap_or(false))
     }
 }
 
@@ -148,7 +160,8 @@ impl ::callback::EnterWindowCb for Handle {}
 impl ::callback::LeaveWindowCb for Handle {}
 /// Note: The wrapped element may not support `HelpCb`.
 impl ::callback::HelpCb for Handle {}
-// TODO impl K_ callbacks when it's implemented.
+/// Note: The wrapped element may not support `KAnyCb`.
+impl ::callback::key::KAnyCb for Handle {}
 
 
 /// Every IUP object is an `Element`.
@@ -270,6 +283,22 @@ pub trait Element : Sized + Copy + Clone {
         }
     }
 
+    /// Sets an interface element attribute from a typed value.
+    ///
+    /// See the [attrib](../attrib/) module for details.
+    fn set_attrib_value<T, S1>(&mut self, name: S1, value: &T) -> Self
+                                        where T: AttribValue, S1: Into<String> {
+        self.set_attrib(name, value.to_attrib())
+    }
+
+    /// Gets an interface element attribute as a typed value.
+    ///
+    /// Returns `None` if the attribute is not set or its value is not valid for `T`.
+    fn attrib_value<T, S1>(&self, name: S1) -> Option<T>
+                                        where T: AttribValue, S1: Into<String> {
+        self.attrib(name).and_then(|s| T::from_attrib(&s))
+    }
+
     /// Sets a raw interface element attribute.
     ///
     /// # Safety
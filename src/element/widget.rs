@@ -3,6 +3,7 @@ use iup_sys;
 use std::result::Result;
 
 use element::{Element, Node};
+use attrib::{Size, RasterSize, Expand, Color, Font};
 
 macro_rules! impl_widget {
     ($ty_path:path, $classname:expr) => {
@@ -79,4 +80,43 @@ pub trait Widget : Element + Node {
         unsafe { iup_sys::IupHide(self.raw()) };
         self.clone()
     }
+
+    impl_attribs! {
+        /// Whether the element can receive user interaction. Inactive elements are usually
+        /// shown in gray. Default `true`.
+        fn active, set_active: bool = "ACTIVE", default true;
+
+        /// Whether the element is visible. Default `true`.
+        ///
+        /// An element is only shown if it and its dialog are visible. Hidden elements still
+        /// take space in the layout unless FLOATING is set.
+        fn visible, set_visible: bool = "VISIBLE", default true;
+
+        /// The tooltip shown when the mouse rests over the element.
+        fn tip, set_tip: String = "TIP";
+
+        /// The background color of the element.
+        fn bgcolor, set_bgcolor: Color = "BGCOLOR";
+
+        /// The foreground color of the element, usually the text color.
+        fn fgcolor, set_fgcolor: Color = "FGCOLOR";
+
+        /// The font of the element.
+        fn font, set_font: Font = "FONT";
+
+        /// The user size of the element in characters units.
+        ///
+        /// It's the minimum size of the element in the layout, the dimensions not set use
+        /// the natural size of the element.
+        fn size, set_size: Size = "SIZE";
+
+        /// The user size of the element in pixels. See `Widget::size`.
+        fn rastersize, set_rastersize: RasterSize = "RASTERSIZE";
+
+        /// Whether the element can fill the empty space of its container.
+        fn expand, set_expand: Expand = "EXPAND";
+
+        /// The name of the element, used to find it with `Node::dialog_child`.
+        fn name, set_name: String = "NAME";
+    }
 }
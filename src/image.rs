@@ -1,6 +1,7 @@
 //! Image elements to be embedded in other controls.
 use iup_sys;
 use Element;
+use attrib::Color;
 
 #[derive(Debug, Copy, Clone)]
 pub struct InPixels<'a, T: 'static>(pub &'a [&'a [T]]);
@@ -74,10 +75,11 @@ impl Image {
     ///
     /// # Panics
     /// Panics if the length of the colors pallet is greater than 256.
-    pub fn set_colors<U>(&mut self, colors: U) -> Image where U: AsRef<[(u8, u8, u8)]> {
-        assert!(colors.as_ref().len() < 256);
+    pub fn set_colors<U, C>(&mut self, colors: U) -> Image
+                                        where U: AsRef<[C]>, C: Into<Color> + Copy {
+        assert!(colors.as_ref().len() <= 256);
     	for (i, &color) in colors.as_ref().iter().enumerate() {
-    		self.set_attrib_rgb(i.to_string(), color);
+    		self.set_attrib_value(i.to_string(), &color.into());
     	}
     	self.clone()
     }
@@ -2,6 +2,7 @@ use iup_sys;
 
 use Handle;
 use Element;
+use attrib::{Margin, Gap, Alignment};
 
 /// A void container for composing elements horizontally.
 /// It is a box that arranges the elements it contains from left to right.
@@ -16,6 +17,26 @@ impl HBox {
         let mut carray = slice_to_ih_array!(elems.as_ref());
         unsafe { HBox::from_raw(iup_sys::IupHboxv(carray.as_mut_ptr())) }
     }
+
+    impl_attribs! {
+        /// The space around the children, in pixels. Default `0x0`.
+        pub fn margin, set_margin: Margin = "MARGIN";
+
+        /// The space around the children, in character units. Default `0x0`.
+        pub fn cmargin, set_cmargin: Margin = "CMARGIN";
+
+        /// The space between the children, in pixels. Default `0`.
+        pub fn gap, set_gap: Gap = "GAP", default Gap(0);
+
+        /// The space between the children, in character units. Default `0`.
+        pub fn cgap, set_cgap: Gap = "CGAP", default Gap(0);
+
+        /// The vertical alignment of the children, `Top`, `Center` or `Bottom`. Default `Top`.
+        pub fn alignment, set_alignment: Alignment = "ALIGNMENT";
+
+        /// Whether all the children get the same size as the largest one. Default `false`.
+        pub fn homogeneous, set_homogeneous: bool = "HOMOGENEOUS", default false;
+    }
 }
 
 impl_widget_container!(HBox, "hbox");
@@ -6,8 +6,10 @@ pub mod vbox;
 pub mod hbox;
 pub mod radio;
 pub mod fill;
+pub mod tabs;
 
 pub use self::vbox::VBox;
 pub use self::hbox::HBox;
 pub use self::radio::Radio;
 pub use self::fill::Fill;
+pub use self::tabs::{Tabs, TabType, TabChangeCb, TabChangePosCb, TabCloseCb};
@@ -2,6 +2,7 @@ use iup_sys;
 use std::ptr;
 
 use Element;
+use control::Toggle;
 
 /// A void container for grouping mutual exclusive toggles.
 /// Only one of its descendent toggles will be active at a time.
@@ -23,6 +24,19 @@ impl Radio {
     pub fn new_empty() -> Radio {
         unsafe { Radio::from_raw(iup_sys::IupRadio(ptr::null_mut())) }
     }
+
+    /// Gets the active toggle, if any.
+    pub fn value(&self) -> Option<Toggle> {
+        match unsafe { iup_sys::IupGetAttribute(self.raw(), cstr!("VALUE_HANDLE")) } {
+            ptr if ptr.is_null() => None,
+            ptr => Some(Toggle::from_raw(ptr as *mut iup_sys::Ihandle)),
+        }
+    }
+
+    /// Makes the specified toggle the active one. The toggle must be inside the radio.
+    pub fn set_value(&mut self, toggle: Toggle) -> Self {
+        self.set_attrib_data("VALUE_HANDLE", toggle.raw() as *const _)
+    }
 }
 
 impl_widget_container!(Radio, "radio");
@@ -0,0 +1,208 @@
+use iup_sys;
+
+use Handle;
+use Element;
+use Orientation;
+use image::ImageElement;
+
+/// A native container for composing elements in hidden layers with only one layer visible,
+/// selected by a tab.
+///
+/// Each child of the container is a tab. Tabs are identified by their position, starting at 0.
+///
+/// See the [IUP Tabs Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iuptabs.html
+pub struct Tabs(*mut iup_sys::Ihandle);
+
+impl Tabs {
+    /// Creates a tabs container with the specified childs, one for each tab.
+    pub fn new<A>(elems: A) -> Tabs where A: AsRef<[Handle]> {
+        let mut carray = slice_to_ih_array!(elems.as_ref());
+        unsafe { Tabs::from_raw(iup_sys::IupTabsv(carray.as_mut_ptr())) }
+    }
+
+    /// Gets the number of tabs.
+    pub fn count(&self) -> usize {
+        self.attrib_parse("COUNT").unwrap_or(0)
+    }
+
+    /// Gets the child of the current tab.
+    pub fn value(&self) -> Option<Handle> {
+        match unsafe { iup_sys::IupGetAttribute(self.raw(), cstr!("VALUE_HANDLE")) } {
+            ptr if ptr.is_null() => None,
+            ptr => Some(Handle::from_raw(ptr as *mut iup_sys::Ihandle)),
+        }
+    }
+
+    /// Changes the current tab to the tab of the specified child.
+    pub fn set_value<E: Element>(&mut self, child: E) -> Self {
+        self.set_attrib_data("VALUE_HANDLE", child.raw() as *const _)
+    }
+
+    /// Gets the position of the current tab.
+    pub fn value_pos(&self) -> Option<usize> {
+        self.attrib_parse("VALUEPOS")
+    }
+
+    /// Changes the current tab by its position.
+    pub fn set_value_pos(&mut self, pos: usize) -> Self {
+        self.set_attrib("VALUEPOS", pos.to_string())
+    }
+
+    /// Gets the title of a tab.
+    pub fn tab_title(&self, pos: usize) -> Option<String> {
+        self.attrib(format!("TABTITLE{}", pos))
+    }
+
+    /// Sets the title of a tab.
+    ///
+    /// The title may contain a mnemonic, see `menu::Item`.
+    pub fn set_tab_title<S: Into<String>>(&mut self, pos: usize, title: S) -> Self {
+        self.set_attrib(format!("TABTITLE{}", pos), title)
+    }
+
+    /// Sets the image shown in a tab, next to its title.
+    pub fn set_tab_image<I: ImageElement>(&mut self, pos: usize, image: I) -> Self {
+        self.set_attrib_handle(format!("TABIMAGE{}", pos), image)
+    }
+
+    /// Whether a tab is visible.
+    pub fn is_tab_visible(&self, pos: usize) -> bool {
+        self.attrib_bool(format!("TABVISIBLE{}", pos)).unwrap_or(true)
+    }
+
+    /// Shows or hides a tab. When the current tab is hidden the next visible tab becomes current.
+    pub fn set_tab_visible(&mut self, pos: usize, visible: bool) -> Self {
+        self.set_attrib(format!("TABVISIBLE{}", pos), if visible { "YES" } else { "NO" })
+    }
+
+    impl_attribs! {
+        /// Whether each tab shows a close button. Default `false`.
+        ///
+        /// See `TabCloseCb` for the behaviour when the button is pressed.
+        ///
+        /// \[Windows and GTK Only\]
+        pub fn show_close, set_show_close: bool = "SHOWCLOSE", default false;
+    }
+
+    /// Gets the side of the container where the tabs are placed.
+    pub fn tab_type(&self) -> TabType {
+        match self.attrib("TABTYPE") {
+            Some(ref s) if s == "BOTTOM" => TabType::Bottom,
+            Some(ref s) if s == "LEFT" => TabType::Left,
+            Some(ref s) if s == "RIGHT" => TabType::Right,
+            _ => TabType::Top,
+        }
+    }
+
+    /// Sets the side of the container where the tabs are placed. Default `TabType::Top`.
+    pub fn set_tab_type(&mut self, tab_type: TabType) -> Self {
+        self.set_attrib("TABTYPE", tab_type.as_str())
+    }
+
+    /// Gets the orientation of the tab titles.
+    pub fn tab_orientation(&self) -> Orientation {
+        match self.attrib("TABORIENTATION") {
+            Some(ref s) if s == "VERTICAL" => Orientation::Vertical,
+            _ => Orientation::Horizontal,
+        }
+    }
+
+    /// Sets the orientation of the tab titles. Default `Orientation::Horizontal`.
+    ///
+    /// Vertical titles are supported only in GTK, in Windows only when the tabs are on the
+    /// left or right sides.
+    pub fn set_tab_orientation(&mut self, orient: Orientation) -> Self {
+        self.set_attrib_data("TABORIENTATION", orient.as_cstr() as *const _)
+    }
+}
+
+impl_widget_container!(Tabs, "tabs");
+impl ::callback::MapCb for Tabs {}
+impl ::callback::UnmapCb for Tabs {}
+impl ::callback::GetFocusCb for Tabs {}
+impl ::callback::KillFocusCb for Tabs {}
+impl ::callback::EnterWindowCb for Tabs {}
+impl ::callback::LeaveWindowCb for Tabs {}
+impl ::callback::HelpCb for Tabs {}
+impl ::callback::key::KAnyCb for Tabs {}
+
+/// Action generated when the right mouse button is pressed over a tab.
+///
+/// The `i32` parameter is the position of the tab.
+impl ::callback::RightClickCb for Tabs {}
+
+/// See the `TabChangeCb` documentation.
+impl self::TabChangeCb for Tabs {}
+
+/// See the `TabChangePosCb` documentation.
+impl self::TabChangePosCb for Tabs {}
+
+/// See the `TabCloseCb` documentation.
+impl self::TabCloseCb for Tabs {}
+
+impl_callback! {
+    #[doc="Called when the user shifts the active tab."]
+    #[doc=""]
+    #[doc="The `Handle` parameter is the child of the new tab and the `Option<Handle>` is the"]
+    #[doc="child of the previous tab, `None` when there was no previous tab."]
+    #[doc=""]
+    #[doc="Not called when the current tab is changed programmatically."]
+    pub trait TabChangeCb where Self: Element {
+        let name = "TABCHANGE_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, new_tab: *mut iup_sys::Ihandle,
+                           old_tab: *mut iup_sys::Ihandle) -> CallbackReturn;
+        fn set_tabchange_cb<F: Callback(Self, Handle, Option<Handle>)>(&mut self, cb: F) -> Self;
+        fn remove_tabchange_cb(&mut self) -> Option<Box<_>>;
+    }
+}
+
+impl_callback! {
+    #[doc="Called when the user shifts the active tab, called only when `TabChangeCb` is not defined."]
+    #[doc=""]
+    #[doc="The `usize` parameter is the position of the new tab and the `Option<usize>` is the"]
+    #[doc="position of the previous tab, `None` when there was no previous tab."]
+    pub trait TabChangePosCb where Self: Element {
+        let name = "TABCHANGEPOS_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, new_pos: c_int, old_pos: c_int) -> CallbackReturn;
+        fn set_tabchangepos_cb<F: Callback(Self, usize, Option<usize>)>(&mut self, cb: F) -> Self;
+        fn remove_tabchangepos_cb(&mut self) -> Option<Box<_>>;
+    }
+}
+
+impl_callback! {
+    #[doc="Called when the user clicks on the close button of a tab, see `Tabs::set_show_close`."]
+    #[doc=""]
+    #[doc="The `usize` parameter is the position of the tab."]
+    #[doc=""]
+    #[doc="By default the tab and its child are destroyed. If `CallbackReturn::Continue` is returned"]
+    #[doc="the tab is hidden instead, and if `CallbackReturn::Ignore` is returned nothing is done."]
+    pub trait TabCloseCb where Self: Element {
+        let name = "TABCLOSE_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, pos: c_int) -> CallbackReturn;
+        fn set_tabclose_cb<F: Callback(Self, usize)>(&mut self, cb: F) -> Self;
+        fn remove_tabclose_cb(&mut self) -> Option<Box<_>>;
+    }
+}
+
+/// The side of a `Tabs` container where the tabs are placed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TabType {
+    Top,
+    Bottom,
+    /// \[Windows and GTK Only\]
+    Left,
+    /// \[Windows and GTK Only\]
+    Right,
+}
+
+impl TabType {
+    fn as_str(self) -> &'static str {
+        match self {
+            TabType::Top => "TOP",
+            TabType::Bottom => "BOTTOM",
+            TabType::Left => "LEFT",
+            TabType::Right => "RIGHT",
+        }
+    }
+}
@@ -39,8 +39,9 @@
 //! control such as a  button click and attributes are the way to set and get specific properties
 //! of the element such as it's design or value.
 //!
-//! Currently attributes are not individual methods specific to each element but that may be a
-//! thing in the future.
+//! The most common attributes have typed methods on each element, such as `Widget::set_active`
+//! or `Dialog::set_title`, while any attribute can still be accessed by name with
+//! `Element::set_attrib` and `Element::attrib`. See the [attrib](attrib/) module.
 //!
 //! The binding is built in a way one can build controls or even the entire window of the
 //! application in a single expression in a very expressive way, for example:
@@ -111,6 +112,9 @@ use std::ptr;
 #[macro_use]
 mod macros;
 
+#[macro_use]
+pub mod attrib;
+
 #[macro_use]
 pub mod element;
 pub use element::{Element, Handle, Guard};
@@ -121,11 +125,15 @@ pub mod callback;
 pub mod dialog;
 pub mod layout;
 pub mod control;
+pub mod menu;
 
 pub mod led;
 pub mod image;
 pub mod timer;
 pub mod clipboard;
+pub mod draw;
+pub mod thread;
+pub mod task;
 
 pub mod prelude;
 
@@ -170,13 +178,13 @@ pub enum InitError {
 ///
 /// ## Blocking
 /// This functin will not return until until a callback returns `CallbackReturn::Close`,
-/// IupExitLoop (TODO) is called, or there are no visible dialogs. 
+/// `exit_loop` is called, or there are no visible dialogs.
 ///
 /// If the `f` closure returns successfully without any visible dialogs and no active timers,
 /// the application will hang and will not be possible to close the main loop. The process will
 /// have to be interrupted by the system.
 ///
-/// When the last visible dialog is hidden the IupExitLoop (TODO) function is automatically called,
+/// When the last visible dialog is hidden the `exit_loop` function is automatically called,
 /// causing this function to return. To avoid that set LOCKLOOP=YES before hiding the last dialog.
 ///
 /// ## Enviroment Variables
@@ -190,6 +198,32 @@ pub enum InitError {
 ///     the driver's version when initializing.  Default: `NO`.
 ///
 pub fn with_iup<F: FnOnce() -> Result<(), String>>(f: F) -> Result<(), InitError> {
+    with_iup_custom_loop(|| {
+        f().map(|_| main_loop())
+    })
+}
+
+/// Initializes IUP toolkit, calls `f` to run the application and closes IUP.
+///
+/// Unlike `with_iup` the main loop is not run, it's up to `f` to process the events, e.g. by
+/// calling `loop_step` periodically from another loop or `main_loop`.
+///
+/// Returns `Ok` if the IUP initialization and `f` were successful. `Err` otherwise.
+///
+/// ```ignore
+/// iup::with_iup_custom_loop(|| {
+///     let mut dialog = Dialog::new(...);
+///     dialog.show()?;
+///     while game.running() {
+///         game.update();
+///         if iup::loop_step() == LoopStatus::Close {
+///             break;
+///         }
+///     }
+///     Ok(())
+/// })
+/// ```
+pub fn with_iup_custom_loop<F: FnOnce() -> Result<(), String>>(f: F) -> Result<(), InitError> {
 
     match unsafe { iup_sys::IupOpen(ptr::null(), ptr::null()) } {
         iup_sys::IUP_NOERROR => {},
@@ -197,6 +231,7 @@ pub fn with_iup<F: FnOnce() -> Result<(), String>>(f: F) -> Result<(), InitError
         iup_sys::IUP_ERROR => return Err(InitError::Error),
         _ => unreachable!(),
     };
+    thread::set_iup_open(true);
 
     // Turn UTF-8 mode ON since Rust uses UTF-8 on strings.
     match element::global("DRIVER").unwrap().as_ref() {
@@ -208,20 +243,82 @@ pub fn with_iup<F: FnOnce() -> Result<(), String>>(f: F) -> Result<(), InitError
     }
 
     let user_result = f();
-    if user_result.is_ok() {
-        // IupMainLoop always returns IUP_NOERROR.
-        unsafe { iup_sys::IupMainLoop(); }
-    }
 
     // perform manual drop_callback! on the global callbacks.
     // also calls our iup-rust specific close callback.
     callback::remove_idle();
     callback::remove_close_cb().map( |mut fbox| fbox.on_callback(()) );
+    thread::set_iup_open(false);
+    task::shutdown();
     unsafe { iup_sys::IupClose(); }
 
     user_result.map_err(|e| InitError::UserError(e))
 }
 
+/// Runs the main event loop, processing events until a callback returns
+/// `CallbackReturn::Close`, `exit_loop` is called, or there are no visible dialogs.
+///
+/// It's called by `with_iup`. It can be called again, e.g. from a callback, to run a nested
+/// loop which returns to the previous loop level, see `main_loop_level`.
+pub fn main_loop() {
+    // IupMainLoop always returns IUP_NOERROR.
+    unsafe { iup_sys::IupMainLoop(); }
+}
+
+/// Processes the pending events and returns without waiting for new events.
+///
+/// This is useful to keep the GUI responsive during a long computation, or to run IUP from
+/// another event loop.
+pub fn loop_step() -> LoopStatus {
+    LoopStatus::from_raw(unsafe { iup_sys::IupLoopStep() })
+}
+
+/// Waits for at least one event and processes the pending events.
+pub fn loop_step_wait() -> LoopStatus {
+    LoopStatus::from_raw(unsafe { iup_sys::IupLoopStepWait() })
+}
+
+/// Terminates the current main loop, returning from `main_loop` or a `DialogElement::popup`.
+///
+/// The loop ends after the current callback returns, like returning `CallbackReturn::Close`.
+pub fn exit_loop() {
+    unsafe { iup_sys::IupExitLoop() };
+}
+
+/// Returns the number of nested main loops currently running, 0 when no loop is running.
+///
+/// Each `DialogElement::popup` runs a nested loop too.
+pub fn main_loop_level() -> i32 {
+    unsafe { iup_sys::IupMainLoopLevel() as i32 }
+}
+
+/// Processes the pending events, including the element updates caused by attribute changes.
+///
+/// Like `loop_step` it can be called from a callback to update the GUI during a long operation,
+/// but it also ensures the pending attribute changes are applied, and it doesn't report whether
+/// the application should close with a `LoopStatus`.
+pub fn flush() {
+    unsafe { iup_sys::IupFlush() };
+}
+
+/// The status after processing events with `loop_step` or `loop_step_wait`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LoopStatus {
+    /// The application should continue running.
+    Continue,
+    /// A callback returned `CallbackReturn::Close` or `exit_loop` was called.
+    Close,
+}
+
+impl LoopStatus {
+    fn from_raw(status: libc::c_int) -> LoopStatus {
+        match status {
+            iup_sys::IUP_CLOSE => LoopStatus::Close,
+            _ => LoopStatus::Continue,
+        }
+    }
+}
+
 /// Returns a string with the IUP version number.
 pub fn version() -> String {
     string_from_cstr!(unsafe { iup_sys::IupVersion() })
@@ -0,0 +1,301 @@
+//! Menus, either as a dialog menu bar or as a popup menu.
+//!
+//! A menu is a `Menu` containing `Item`s, `Separator`s and `Submenu`s, each submenu with
+//! a menu of its own. The `menu!` macro can be used to build nested menus in a single
+//! expression:
+//!
+//! ```ignore
+//! let menubar = menu![
+//!     Submenu::with_title("&File", menu![
+//!         Item::with_title("&Open").set_accel(Key::ctrl('o'))
+//!                                  .set_action(|_| open_file()),
+//!         Separator::new(),
+//!         Item::with_title("E&xit").set_action(|_| CallbackReturn::Close),
+//!     ]),
+//!     Submenu::with_title("&View", menu![
+//!         Item::with_title("&Status Bar").set_checkable(true).set_checked(true),
+//!     ]),
+//! ];
+//!
+//! Dialog::new(child).set_menu(menubar);
+//! ```
+//!
+//! # Ownership
+//!
+//! A menu associated with a dialog (`Dialog::set_menu`) is destroyed with the dialog, but
+//! a popup menu must be destroyed manually after being used.
+//!
+//! See the [IUP Menu Documentation][1].
+//! [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupmenu.html
+use iup_sys;
+use std::ffi::CString;
+use std::ptr;
+
+use Element;
+use Handle;
+use callback::key::Key;
+use dialog::DialogPos;
+use image::ImageElement;
+
+/// Creates a `Menu` from a list of items, separators and submenus.
+///
+/// This is just like `elements!` but wraps the elements into a `Menu`.
+#[macro_export]
+macro_rules! menu {
+    ($($elem:expr),*,) => { menu! [ $($elem),* ] };
+    ($($elem:expr),*) => { $crate::menu::Menu::new(elements! [ $($elem),* ]) };
+}
+
+/// A menu containing items, separators and submenus.
+///
+/// It can be used as the menu bar of a dialog (`Dialog::set_menu`), as the menu of a
+/// `Submenu` or shown anywhere as a popup menu (`Menu::popup`).
+pub struct Menu(*mut iup_sys::Ihandle);
+
+impl Menu {
+    /// Creates a menu with the specified items, separators and submenus.
+    pub fn new<A>(elems: A) -> Menu where A: AsRef<[Handle]> {
+        let mut carray = slice_to_ih_array!(elems.as_ref());
+        unsafe { Menu::from_raw(iup_sys::IupMenuv(carray.as_mut_ptr())) }
+    }
+
+    /// Creates a menu with no items.
+    pub fn new_empty() -> Menu {
+        unsafe { Menu::from_raw(iup_sys::IupMenuv([ptr::null_mut()].as_mut_ptr())) }
+    }
+
+    impl_attribs! {
+        /// Whether the checkable items of this menu behave as a radio, i.e. only one item can
+        /// be checked at a time. Default `false`.
+        pub fn radio, set_radio: bool = "RADIO", default false;
+    }
+
+    /// Shows the menu as a popup at the specified position.
+    ///
+    /// Returns after a menu item is selected or the menu is dismissed.
+    ///
+    /// `DialogPos::Current` is not valid for menus.
+    ///
+    /// # Panics
+    /// Panics if `x` or `y` is `Current`, if `x` is either `Bottom` or `Top` or if `y` is either
+    /// `Left` or `Right`.
+    pub fn popup(&mut self, x: DialogPos, y: DialogPos) -> Result<Self, Self> {
+        assert!(x != DialogPos::Current && y != DialogPos::Current);
+        match unsafe { iup_sys::IupPopup(self.raw(), x.to_raw_x(), y.to_raw_y()) } {
+            iup_sys::IUP_NOERROR => Ok(*self),
+            _ => Err(*self),
+        }
+    }
+}
+
+impl_element!(Menu, "menu");
+impl ::element::Node for Menu {}
+impl ::element::Container for Menu {}
+impl ::callback::MapCb for Menu {}
+impl ::callback::UnmapCb for Menu {}
+
+/// See the `OpenCb` documentation.
+impl self::OpenCb for Menu {}
+
+/// See the `MenuCloseCb` documentation.
+impl self::MenuCloseCb for Menu {}
+
+
+/// An item of a menu.
+///
+/// The title of the item may contain a mnemonic, that is a letter preceded by `&` that can be
+/// used to select the item with the keyboard while the menu is open, e.g. `"&Save"`. To show a
+/// literal `&` use `&&`.
+pub struct Item(*mut iup_sys::Ihandle);
+
+impl Item {
+    /// Creates a item with the specified title.
+    pub fn with_title<S: Into<String>>(title: S) -> Item {
+        let ctitle = CString::new(title.into()).unwrap();
+        unsafe { Item::from_raw(iup_sys::IupItem(ctitle.as_ptr(), ptr::null())) }
+    }
+
+    /// Gets the title of the item, without the accelerator text.
+    pub fn title(&self) -> String {
+        self.attrib("TITLE").map(|title| split_accel(&title).0.to_owned()).unwrap_or_default()
+    }
+
+    /// Sets the title of the item, preserving the accelerator text.
+    pub fn set_title<S: Into<String>>(&mut self, title: S) -> Self {
+        let accel = self.accel_text();
+        self.set_attrib("TITLE", join_accel(title.into(), accel))
+    }
+
+    /// Shows the specified key as the accelerator of the item, right aligned next to its title.
+    ///
+    /// This is only the text, the key is not handled by the menu. Use `Dialog::set_shortcut`
+    /// to associate an action with the key.
+    pub fn set_accel(&mut self, key: Key) -> Self {
+        let title = self.title();
+        self.set_attrib("TITLE", join_accel(title, Some(key.to_string())))
+    }
+
+    /// Gets the accelerator text of the item, if any.
+    pub fn accel_text(&self) -> Option<String> {
+        self.attrib("TITLE").and_then(|title| split_accel(&title).1.map(|s| s.to_owned()))
+    }
+
+    impl_attribs! {
+        /// Whether the item automatically toggles its checked state when activated. Default
+        /// `false`.
+        ///
+        /// When the item is inside a radio menu (`Menu::set_radio`) activating it checks the
+        /// item and unchecks the others.
+        pub fn checkable, set_checkable: bool = "AUTOTOGGLE", default false;
+
+        /// Whether the item can be activated by the user. Default `true`.
+        pub fn active, set_active: bool = "ACTIVE", default true;
+    }
+
+    /// Whether the item is checked.
+    pub fn is_checked(&self) -> bool {
+        self.attrib_bool("VALUE").unwrap_or(false)
+    }
+
+    /// Checks or unchecks the item, showing a check mark next to its title.
+    pub fn set_checked(&mut self, checked: bool) -> Self {
+        self.set_attrib("VALUE", if checked { "ON" } else { "OFF" })
+    }
+
+    /// Sets the image shown next to the title of the item.
+    ///
+    /// \[Windows and GTK Only\]
+    pub fn set_image<I: ImageElement>(&mut self, image: I) -> Self {
+        self.set_attrib_handle("IMAGE", image)
+    }
+
+    /// Sets the image shown next to the title of the item when it's checked.
+    ///
+    /// \[Windows Only\]
+    pub fn set_image_checked<I: ImageElement>(&mut self, image: I) -> Self {
+        self.set_attrib_handle("IMPRESS", image)
+    }
+
+}
+
+impl_element!(Item, "item");
+impl ::element::Node for Item {}
+impl ::callback::MapCb for Item {}
+impl ::callback::UnmapCb for Item {}
+impl ::callback::HelpCb for Item {}
+
+/// Action generated when the item is selected.
+///
+/// `CallbackReturn::Close` will be processed, closing the dialog of the menu or ending the
+/// current popup level.
+impl ::callback::Action for Item {}
+
+/// See the `HighlightCb` documentation.
+impl self::HighlightCb for Item {}
+
+
+/// A menu item that, when selected, opens another menu.
+///
+/// The title follows the same mnemonic rules of `Item`.
+pub struct Submenu(*mut iup_sys::Ihandle);
+
+impl Submenu {
+    /// Creates a submenu with the specified title and menu.
+    pub fn with_title<S: Into<String>>(title: S, menu: Menu) -> Submenu {
+        let ctitle = CString::new(title.into()).unwrap();
+        unsafe { Submenu::from_raw(iup_sys::IupSubmenu(ctitle.as_ptr(), menu.raw())) }
+    }
+
+    /// Gets the menu opened by this submenu.
+    pub fn menu(&self) -> Option<Menu> {
+        match unsafe { iup_sys::IupGetChild(self.raw(), 0) } {
+            ptr if ptr.is_null() => None,
+            ptr => Some(Menu::from_raw(ptr)),
+        }
+    }
+
+    /// Sets the image shown next to the title of the submenu.
+    ///
+    /// \[Windows and GTK Only\]
+    pub fn set_image<I: ImageElement>(&mut self, image: I) -> Self {
+        self.set_attrib_handle("IMAGE", image)
+    }
+
+    impl_attribs! {
+        /// Whether the submenu can be opened by the user. Default `true`.
+        pub fn active, set_active: bool = "ACTIVE", default true;
+    }
+}
+
+impl_element!(Submenu, "submenu");
+impl ::element::Node for Submenu {}
+impl ::element::Container for Submenu {}
+impl ::callback::MapCb for Submenu {}
+impl ::callback::UnmapCb for Submenu {}
+impl ::callback::HelpCb for Submenu {}
+
+/// See the `HighlightCb` documentation.
+impl self::HighlightCb for Submenu {}
+
+
+/// A line separating groups of items in a menu.
+pub struct Separator(*mut iup_sys::Ihandle);
+
+impl Separator {
+    /// Creates a menu separator.
+    pub fn new() -> Separator {
+        unsafe { Separator::from_raw(iup_sys::IupSeparator()) }
+    }
+}
+
+impl_element!(Separator, "separator");
+impl ::element::Node for Separator {}
+impl ::callback::MapCb for Separator {}
+impl ::callback::UnmapCb for Separator {}
+
+
+impl_callback! {
+    #[doc="Called when the item or submenu is highlighted, either by the mouse or the keyboard."]
+    pub trait HighlightCb where Self: Element {
+        let name = "HIGHLIGHT_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle) -> CallbackReturn;
+        fn set_highlight_cb<F: Callback(Self)>(&mut self, cb: F) -> Self;
+        fn remove_highlight_cb(&mut self) -> Option<Box<_>>;
+    }
+}
+
+impl_callback! {
+    #[doc="Called just before the menu is opened."]
+    #[doc=""]
+    #[doc="This is a good place to update the state of the items, such as whether they're"]
+    #[doc="checked or active."]
+    pub trait OpenCb where Self: Element {
+        let name = "OPEN_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle) -> CallbackReturn;
+        fn set_open_cb<F: Callback(Self)>(&mut self, cb: F) -> Self;
+        fn remove_open_cb(&mut self) -> Option<Box<_>>;
+    }
+}
+
+impl_callback! {
+    #[doc="Called just after the menu is closed."]
+    pub trait MenuCloseCb where Self: Element {
+        let name = "MENUCLOSE_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle) -> CallbackReturn;
+        fn set_menuclose_cb<F: Callback(Self)>(&mut self, cb: F) -> Self;
+        fn remove_menuclose_cb(&mut self) -> Option<Box<_>>;
+    }
+}
+
+/// Splits a item title in the form `title\taccel` into its title and accelerator text.
+fn split_accel(title: &str) -> (&str, Option<&str>) {
+    let mut it = title.splitn(2, '\t');
+    (it.next().unwrap_or(""), it.next())
+}
+
+fn join_accel(title: String, accel: Option<String>) -> String {
+    match accel {
+        Some(accel) => format!("{}\t{}", title, accel),
+        None => title,
+    }
+}
@@ -24,8 +24,16 @@ pub use dialog::DialogElement;
 // Callbacks
 pub use callback::{CallbackReturn, Action, ActionCb, DestroyCb};
 pub use callback::{MapCb, UnmapCb, GetFocusCb, KillFocusCb, EnterWindowCb, LeaveWindowCb, HelpCb};
-pub use callback::{CaretCb, SpinCb, ValueChangedCb, DropFilesCb};
+pub use callback::{CaretCb, SpinCb, ValueChangedCb, ValueChangingCb, DropFilesCb};
+pub use callback::{RightClickCb, DragDropCb};
 pub use callback::{CloseCb, MoveCb, ResizeCb};
-pub use callback::button::{ButtonCb, MotionCb};
-pub use control::{TextAction, ToggleAction, ListAction};
+pub use callback::button::{ButtonCb, MotionCb, WheelCb};
+pub use callback::key::KAnyCb;
+pub use control::{TextAction, ToggleAction, ListAction, CanvasAction, ScrollCb};
+pub use control::{SelectionCb, MultiSelectionCb, BranchOpenCb, BranchCloseCb, ExecuteLeafCb, RenameCb};
+pub use control::{DblClickCb, MultiSelectCb, EditCb, DropDownCb};
+pub use layout::{TabChangeCb, TabChangePosCb, TabCloseCb};
 pub use dialog::{CopyDataCb, MdiActivateCb, ShowCb, TrayClickCb};
+pub use dialog::{ColorUpdateCb, FileCb, CancelCb};
+pub use menu::{HighlightCb, OpenCb, MenuCloseCb};
+pub use thread::PostMessageCb;
@@ -0,0 +1,155 @@
+//! Futures executed in the GUI thread.
+//!
+//! `spawn_local` runs a future in the GUI thread, driven by the main loop. The future can then
+//! wait for events without blocking the GUI, e.g. with `timer::sleep` or
+//! `DialogElement::popup_async`, and use elements freely since it never leaves the GUI thread.
+//!
+//! ```ignore
+//! task::spawn_local(async move {
+//!     label.set_title("Working...");
+//!     timer::sleep(1000).await;
+//!     label.set_title("Done");
+//! });
+//! ```
+//!
+//! Woken futures are polled through a `UiHandle`, so the executor costs nothing while the
+//! futures wait, and futures can also be woken from other threads, e.g. by a channel or a
+//! thread pool.
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Wake, Waker};
+
+use thread::UiHandle;
+
+type Task = Pin<Box<dyn Future<Output = ()>>>;
+
+struct Executor {
+    tasks: RefCell<HashMap<usize, Task>>,
+    /// The tasks being polled, out of `tasks`.
+    polling: RefCell<Vec<usize>>,
+    /// The tasks woken while being polled by an outer tick, polled again once that poll ends.
+    rewoken: RefCell<Vec<usize>>,
+    next_id: Cell<usize>,
+    /// Created by the first spawn of an IUP session.
+    ready: RefCell<Option<Arc<ReadyQueue>>>,
+}
+
+thread_local! {
+    static EXECUTOR: Executor = Executor {
+        tasks: RefCell::new(HashMap::new()),
+        polling: RefCell::new(Vec::new()),
+        rewoken: RefCell::new(Vec::new()),
+        next_id: Cell::new(0),
+        ready: RefCell::new(None),
+    };
+}
+
+/// The ids of the woken tasks, shared with their wakers.
+struct ReadyQueue {
+    ids: Mutex<VecDeque<usize>>,
+    handle: UiHandle,
+}
+
+impl ReadyQueue {
+    /// Queues tasks to be polled, posting a tick to the GUI thread unless one is pending.
+    fn push<I: IntoIterator<Item = usize>>(&self, ids: I) {
+        let mut queue = self.ids.lock().unwrap_or_else(|e| e.into_inner());
+        let was_empty = queue.is_empty();
+        queue.extend(ids);
+        if was_empty && !queue.is_empty() {
+            // Fails only after IUP is closed, when the tasks are already dropped.
+            let _ = self.handle.post(tick);
+        }
+    }
+}
+
+/// Wakes a task by queuing its id to be polled in the next tick.
+struct TaskWaker {
+    id: usize,
+    ready: Arc<ReadyQueue>,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.ready.push(Some(self.id));
+    }
+}
+
+/// Runs a future in the GUI thread.
+///
+/// The future is first polled by the main loop, after the current callback returns, and
+/// dropped when completed. Must be called in the GUI thread while IUP is open.
+pub fn spawn_local<F>(future: F) where F: Future<Output = ()> + 'static {
+    let (id, ready) = EXECUTOR.with(|executor| {
+        let id = executor.next_id.get();
+        executor.next_id.set(id.wrapping_add(1));
+        executor.tasks.borrow_mut().insert(id, Box::pin(future));
+        let ready = executor.ready.borrow_mut().get_or_insert_with(|| {
+            Arc::new(ReadyQueue { ids: Mutex::new(VecDeque::new()), handle: UiHandle::new() })
+        }).clone();
+        (id, ready)
+    });
+    ready.push(Some(id));
+}
+
+/// Polls the woken tasks.
+fn tick() {
+    let ready = match EXECUTOR.with(|executor| executor.ready.borrow().clone()) {
+        Some(ready) => ready,
+        None => return,
+    };
+    let waiting = ready.ids.lock().unwrap_or_else(|e| e.into_inner()).drain(..).collect::<Vec<_>>();
+
+    for id in waiting {
+        // The task is taken out while polled, since polling may spawn tasks or run a nested
+        // main loop (e.g. a popup) that ticks again.
+        let task = EXECUTOR.with(|executor| {
+            if executor.polling.borrow().contains(&id) {
+                // Woken while being polled by an outer tick, poll it again after that.
+                executor.rewoken.borrow_mut().push(id);
+                return None;
+            }
+            let task = executor.tasks.borrow_mut().remove(&id);
+            if task.is_some() {
+                executor.polling.borrow_mut().push(id);
+            }
+            task
+        });
+        if let Some(mut task) = task {
+            let waker = Waker::from(Arc::new(TaskWaker { id, ready: ready.clone() }));
+            let pending = task.as_mut().poll(&mut Context::from_waker(&waker)).is_pending();
+            let rewoken = EXECUTOR.with(|executor| {
+                executor.polling.borrow_mut().retain(|&polled| polled != id);
+                if pending {
+                    executor.tasks.borrow_mut().insert(id, task);
+                }
+                let mut rewoken = executor.rewoken.borrow_mut();
+                let len = rewoken.len();
+                rewoken.retain(|&woken| woken != id);
+                pending && rewoken.len() != len
+            });
+            if rewoken {
+                ready.push(Some(id));
+            }
+        }
+    }
+}
+
+/// Drops the pending tasks, called before IUP is closed.
+#[doc(hidden)]
+pub fn shutdown() {
+    let tasks = EXECUTOR.with(|executor| {
+        executor.ready.replace(None);
+        executor.rewoken.borrow_mut().clear();
+        executor.tasks.replace(HashMap::new())
+    });
+    // Dropped outside of the executor in case a task spawns on drop.
+    drop(tasks);
+}
@@ -0,0 +1,218 @@
+//! Communication with the GUI thread from other threads.
+//!
+//! Elements can only be used in the thread running IUP, they are neither `Send` nor `Sync`.
+//! Other threads can instead post closures to be executed in the GUI thread with a `UiHandle`,
+//! or messages to an element with a `MessageSender`:
+//!
+//! ```ignore
+//! let ui = UiHandle::new();
+//! Label::new().add_handle_name("result");
+//!
+//! thread::spawn(move || {
+//!     let result = long_computation();
+//!     ui.post(move || {
+//!         if let Some(Ok(mut label)) = Handle::from_named("result").map(Label::from_handle) {
+//!             label.set_title(result);
+//!         }
+//!     }).ok();
+//! });
+//! ```
+//!
+//! Since the closures must be `Send` they can't capture elements, elements can be found in the
+//! GUI thread by their handle names instead (`Element::add_handle_name`).
+//!
+//! The closures and messages are processed by the main loop in the order they were posted.
+//!
+//! Posting requires IUP 3.18 or newer (`IupPostMessage`).
+use iup_sys;
+use libc::{c_char, c_int, c_double, c_void};
+use std::collections::VecDeque;
+use std::error::Error;
+use std::ffi::CString;
+use std::fmt;
+use std::ptr;
+use std::sync::{Arc, Mutex, RwLock};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use Element;
+
+extern "C" {
+    fn IupPostMessage(ih: *mut iup_sys::Ihandle, s: *const c_char, i: c_int, d: c_double,
+                      p: *mut c_void);
+}
+
+/// The id of the current IUP session, i.e. from `IupOpen` to `IupClose`, or 0 if IUP is not
+/// open. Handles are bound to the session they were created in, since their elements don't
+/// outlive it.
+///
+/// Posting holds a read lock so IUP is not closed while a message is being posted.
+static SESSION: RwLock<u64> = RwLock::new(0);
+
+/// The id of the last session.
+static LAST_SESSION: AtomicU64 = AtomicU64::new(0);
+
+/// The receivers of the `UiHandle`s of the current session, destroyed when it ends.
+static RECEIVERS: Mutex<Vec<usize>> = Mutex::new(Vec::new());
+
+/// Starts or ends a session, called after IUP is opened and before it's closed.
+#[doc(hidden)]
+pub fn set_iup_open(open: bool) {
+    let mut session = SESSION.write().unwrap_or_else(|e| e.into_inner());
+    if open {
+        *session = LAST_SESSION.fetch_add(1, Ordering::Relaxed) + 1;
+    } else {
+        *session = 0;
+        for receiver in RECEIVERS.lock().unwrap_or_else(|e| e.into_inner()).drain(..) {
+            unsafe { Receiver::from_raw_unchecked(receiver as *mut iup_sys::Ihandle) }.destroy();
+        }
+    }
+}
+
+/// Gets the id of the current session, must be called in the GUI thread while IUP is open.
+fn current_session() -> u64 {
+    *SESSION.read().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Posts a message to `ih` if the session it belongs to is still open.
+fn post_message(session: u64, ih: usize, s: Option<&str>, i: i32, d: f64)
+                                                                -> Result<(), PostError> {
+    let cs = match s.map(CString::new) {
+        Some(Err(_)) => return Err(PostError::Nul),
+        Some(Ok(cs)) => Some(cs),
+        None => None,
+    };
+    let current = SESSION.read().unwrap_or_else(|e| e.into_inner());
+    if *current == 0 || *current != session {
+        return Err(PostError::Closed);
+    }
+    unsafe {
+        let s = cs.as_ref().map_or(ptr::null(), |s| s.as_ptr());
+        IupPostMessage(ih as *mut iup_sys::Ihandle, s, i, d, ptr::null_mut());
+    }
+    Ok(())
+}
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// A handle to post closures to be executed in the GUI thread.
+///
+/// The handle is `Send` and cheap to clone, clones post to the same queue.
+#[derive(Clone)]
+pub struct UiHandle {
+    session: u64,
+    receiver: usize,
+    queue: Arc<Mutex<VecDeque<Job>>>,
+}
+
+impl UiHandle {
+    /// Creates a handle, must be called in the GUI thread while IUP is open.
+    ///
+    /// Each handle owns a hidden element that receives its messages, so prefer cloning an
+    /// existing handle to creating new ones. The element is destroyed when IUP is closed.
+    pub fn new() -> UiHandle {
+        let queue = Arc::new(Mutex::new(VecDeque::<Job>::new()));
+        let jobs = queue.clone();
+        let receiver = unsafe { Receiver::from_raw(iup_sys::IupUser()) }
+                           .set_postmessage_cb(move |_: (Receiver, Option<String>, i32, f64)| {
+            // Take the job out of the queue before running it, so it may post other jobs.
+            let job = jobs.lock().unwrap_or_else(|e| e.into_inner()).pop_front();
+            if let Some(job) = job {
+                job();
+            }
+        });
+        let receiver = receiver.raw() as usize;
+        RECEIVERS.lock().unwrap_or_else(|e| e.into_inner()).push(receiver);
+        UiHandle { session: current_session(), receiver, queue }
+    }
+
+    /// Posts a closure to be executed in the GUI thread by the main loop.
+    ///
+    /// Fails if IUP was closed since the handle was created, e.g. after `with_iup` returned.
+    pub fn post<F>(&self, f: F) -> Result<(), PostError> where F: FnOnce() + Send + 'static {
+        // The queue stays locked until the job is pushed, so the receiver can't handle the
+        // message before the job is there.
+        let mut queue = self.queue.lock().unwrap_or_else(|e| e.into_inner());
+        post_message(self.session, self.receiver, None, 0, 0.0)?;
+        queue.push_back(Box::new(f));
+        Ok(())
+    }
+}
+
+/// A handle to post messages to an element, handled by its `PostMessageCb`.
+///
+/// The sender is `Send` and cheap to clone.
+#[derive(Debug, Copy, Clone)]
+pub struct MessageSender {
+    session: u64,
+    target: usize,
+}
+
+impl MessageSender {
+    /// Creates a sender to the specified element, must be called in the GUI thread while IUP
+    /// is open.
+    ///
+    /// # Safety
+    /// The element must not be destroyed while the sender, or any of its copies, is used.
+    /// Senders are invalidated when IUP is closed, so elements destroyed by `IupClose` are
+    /// never sent to.
+    pub unsafe fn new<E: PostMessageCb>(target: &E) -> MessageSender {
+        MessageSender { session: current_session(), target: target.raw() as usize }
+    }
+
+    /// Posts a message with a string, an integer and a real to the element.
+    ///
+    /// Fails if IUP was closed since the sender was created, e.g. after `with_iup` returned, or
+    /// if the string contains a nul character.
+    pub fn send(&self, s: Option<&str>, i: i32, d: f64) -> Result<(), PostError> {
+        post_message(self.session, self.target, s, i, d)
+    }
+}
+
+/// The error returned when posting to the GUI thread fails.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PostError {
+    /// IUP is not open, or was closed since the handle was created.
+    Closed,
+    /// The string of the message contains a nul character.
+    Nul,
+}
+
+impl fmt::Display for PostError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(match *self {
+            PostError::Closed => "IUP is not open",
+            PostError::Nul => "the message string contains a nul character",
+        })
+    }
+}
+
+impl Error for PostError {}
+
+/// The hidden element receiving the messages of a `UiHandle`.
+struct Receiver(*mut iup_sys::Ihandle);
+
+impl_element!(Receiver, "user");
+impl self::PostMessageCb for Receiver {}
+
+/// See the `PostMessageCb` documentation.
+impl self::PostMessageCb for ::dialog::Dialog {}
+
+impl_callback! {
+    #[doc="Called in the GUI thread when a message is posted to the element with `MessageSender`."]
+    #[doc=""]
+    #[doc="The parameters are the string, integer and real of the message."]
+    pub trait PostMessageCb where Self: Element {
+        let name = "POSTMESSAGE_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, s: *const c_char, i: c_int, d: c_double,
+                           p: *mut c_void) -> CallbackReturn;
+        fn set_postmessage_cb<F: Callback(Self, Option<String>, i32, f64)>(&mut self, cb: F)
+                                                                                -> Self;
+        fn remove_postmessage_cb(&mut self) -> Option<Box<_>>;
+
+        fn resolve_args(elem: Self, s: *const c_char, i: c_int, d: c_double, _p: *mut c_void)
+                                            -> (Self, Option<String>, i32, f64) {
+            let s = if s.is_null() { None } else { Some(string_from_cstr!(s)) };
+            (elem, s, i, d)
+        }
+    }
+}
@@ -1,8 +1,14 @@
 //! Timer to periodically execute an action.
 use iup_sys;
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
 
 use Element;
 use Guard;
+use callback::ActionCb;
 
 /// A timer which periodically invokes a callback when the time is up.
 ///
@@ -23,16 +29,11 @@ impl Timer {
         )
     }
 
-    /// Gets the set time interval in milliseconds or `None` if not set.
-    pub fn time(&self) -> Option<u32> {
-        self.attrib_parse("TIME")
-    }
-
-    /// Sets the time interval in milliseconds.
-    ///
-    /// In Windows the minimum value is 10ms.
-    pub fn set_time(&mut self, time: u32) -> Self {
-        self.set_attrib("TIME", time.to_string())
+    impl_attribs! {
+        /// The time interval in milliseconds, `None` if not set.
+        ///
+        /// In Windows the minimum value is 10ms.
+        pub fn time, set_time: u32 = "TIME";
     }
 
     /// Starts the timer.
@@ -63,3 +64,50 @@ impl_element!(Timer, "timer");
 ///
 /// `CallbackReturn::Close` will be processed.
 impl ::callback::ActionCb for Timer {}
+
+/// Returns a future that completes after the specified time in milliseconds.
+///
+/// The time starts when the future is first polled. It must be used in the GUI thread, see
+/// `task::spawn_local`.
+pub fn sleep(time: u32) -> Sleep {
+    Sleep { time, timer: None, state: Rc::new(RefCell::new(SleepState::default())) }
+}
+
+/// A future that completes after some time, see `sleep`.
+pub struct Sleep {
+    time: u32,
+    timer: Option<Guard<Timer>>,
+    state: Rc<RefCell<SleepState>>,
+}
+
+#[derive(Default)]
+struct SleepState {
+    done: bool,
+    waker: Option<Waker>,
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        if self.time == 0 || self.state.borrow().done {
+            return Poll::Ready(());
+        }
+
+        self.state.borrow_mut().waker = Some(cx.waker().clone());
+        if self.timer.is_none() {
+            let state = self.state.clone();
+            let mut timer = Timer::new();
+            timer.set_time(self.time).set_action_cb(move |(mut timer,): (Timer,)| {
+                timer.stop();
+                let mut state = state.borrow_mut();
+                state.done = true;
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+            }).run();
+            self.timer = Some(timer);
+        }
+        Poll::Pending
+    }
+}